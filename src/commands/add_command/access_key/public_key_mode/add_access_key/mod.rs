@@ -57,7 +57,7 @@ impl AddAccessKeyAction {
     ) -> color_eyre::eyre::Result<Self> {
         let public_key: near_crypto::PublicKey = match item.public_key {
             Some(cli_public_key) => cli_public_key,
-            None => AddAccessKeyAction::input_public_key(),
+            None => AddAccessKeyAction::input_public_key()?,
         };
         let permission: AccessKeyPermission = match item.permission {
             Some(cli_permission) => {
@@ -74,11 +74,12 @@ impl AddAccessKeyAction {
 }
 
 impl AddAccessKeyAction {
-    fn input_public_key() -> near_crypto::PublicKey {
-        Input::new()
+    fn input_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        crate::common::ensure_interactive("public-key")?;
+        Ok(Input::new()
             .with_prompt("Enter a public key for this access key")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -197,6 +198,7 @@ impl AccessKeyPermission {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("permission")?;
         let select_permission = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Select a permission that you want to add to the access key:")
             .items(&permissions)
@@ -204,16 +206,16 @@ impl AccessKeyPermission {
             .interact()
             .unwrap();
         match variants[select_permission] {
-            AccessKeyPermissionDiscriminants::GrantFunctionCallAccess => Ok(Self::from(
+            AccessKeyPermissionDiscriminants::GrantFunctionCallAccess => Self::from(
                 CliAccessKeyPermission::GrantFunctionCallAccess(Default::default()),
                 connection_config,
                 sender_account_id,
-            )?),
-            AccessKeyPermissionDiscriminants::GrantFullAccess => Ok(Self::from(
+            ),
+            AccessKeyPermissionDiscriminants::GrantFullAccess => Self::from(
                 CliAccessKeyPermission::GrantFullAccess(Default::default()),
                 connection_config,
                 sender_account_id,
-            )?),
+            ),
         }
     }
 }
@@ -86,6 +86,7 @@ impl PublicKeyMode {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("public-key-mode")?;
         let select_mode = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Select a permission that you want to add to the access key:")
             .items(&modes)
@@ -93,16 +94,16 @@ impl PublicKeyMode {
             .interact()
             .unwrap();
         match variants[select_mode] {
-            PublicKeyModeDiscriminants::PublicKey => Ok(Self::from(
+            PublicKeyModeDiscriminants::PublicKey => Self::from(
                 CliPublicKeyMode::PublicKey(Default::default()),
                 connection_config,
                 sender_account_id,
-            )?),
-            PublicKeyModeDiscriminants::GenerateKeypair => Ok(Self::from(
+            ),
+            PublicKeyModeDiscriminants::GenerateKeypair => Self::from(
                 CliPublicKeyMode::GenerateKeypair(Default::default()),
                 connection_config,
                 sender_account_id,
-            )?),
+            ),
         }
     }
 
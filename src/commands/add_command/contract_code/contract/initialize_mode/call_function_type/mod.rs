@@ -77,21 +77,21 @@ impl CallFunctionAction {
     ) -> color_eyre::eyre::Result<Self> {
         let method_name: String = match item.method_name {
             Some(cli_method_name) => cli_method_name,
-            None => CallFunctionAction::input_method_name(),
+            None => CallFunctionAction::input_method_name()?,
         };
         let args: Vec<u8> = match item.args {
             Some(cli_args) => cli_args.into_bytes(),
-            None => CallFunctionAction::input_args(),
+            None => CallFunctionAction::input_args()?,
         };
         let gas: near_primitives::types::Gas = match item.gas {
             Some(cli_gas) => match cli_gas {
                 crate::common::NearGas { inner: num } => num,
             },
-            None => CallFunctionAction::input_gas(),
+            None => CallFunctionAction::input_gas()?,
         };
         let deposit: near_primitives::types::Balance = match item.deposit {
             Some(cli_deposit) => cli_deposit.to_yoctonear(),
-            None => CallFunctionAction::input_deposit(),
+            None => CallFunctionAction::input_deposit()?,
         };
         let sign_option = match item.sign_option {
             Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(cli_sign_transaction, connection_config, sender_account_id)?,
@@ -108,17 +108,19 @@ impl CallFunctionAction {
 }
 
 impl CallFunctionAction {
-    fn input_method_name() -> String {
+    fn input_method_name() -> color_eyre::eyre::Result<String> {
         println!();
-        Input::new()
+        crate::common::ensure_interactive("method-name")?;
+        Ok(Input::new()
             .with_prompt("Enter a method name")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
-    fn input_gas() -> near_primitives::types::Gas {
+    fn input_gas() -> color_eyre::eyre::Result<near_primitives::types::Gas> {
         println!();
         let gas: u64 = loop {
+            crate::common::ensure_interactive("gas")?;
             let input_gas: crate::common::NearGas = Input::new()
                 .with_prompt("Enter a gas for function")
                 .with_initial_text("100 TeraGas")
@@ -133,20 +135,22 @@ impl CallFunctionAction {
                 println!("You need to enter a value of no more than 200 TERAGAS")
             }
         };
-        gas
+        Ok(gas)
     }
 
-    fn input_args() -> Vec<u8> {
+    fn input_args() -> color_eyre::eyre::Result<Vec<u8>> {
         println!();
+        crate::common::ensure_interactive("args")?;
         let input: String = Input::new()
             .with_prompt("Enter args for function")
             .interact_text()
             .unwrap();
-        input.into_bytes()
+        Ok(input.into_bytes())
     }
 
-    fn input_deposit() -> near_primitives::types::Balance {
+    fn input_deposit() -> color_eyre::eyre::Result<near_primitives::types::Balance> {
         println!();
+        crate::common::ensure_interactive("deposit")?;
         let deposit: crate::common::NearBalance = Input::new()
             .with_prompt(
                 "Enter a deposit for function (example: 10NEAR or 0.5near or 10000yoctonear).",
@@ -154,7 +158,7 @@ impl CallFunctionAction {
             .with_initial_text("0 NEAR")
             .interact_text()
             .unwrap();
-        deposit.to_yoctonear()
+        Ok(deposit.to_yoctonear())
     }
 
     pub async fn process(
@@ -80,6 +80,7 @@ impl NextAction {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("next-action")?;
         let selected_action = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Do you want to choose next action")
             .items(&actions)
@@ -92,11 +93,7 @@ impl NextAction {
                 CliNextAction::NoInitialize(Default::default())
             }
         };
-        Ok(Self::from(
-            cli_action,
-            connection_config,
-            sender_account_id,
-        )?)
+        Self::from(cli_action, connection_config, sender_account_id)
     }
 
     pub async fn process(
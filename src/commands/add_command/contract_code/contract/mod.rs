@@ -61,6 +61,7 @@ impl Contract {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("contract")?;
         let selected_contract = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("To deploy contract code you will need to choose next action")
             .items(&contracts)
@@ -70,11 +71,7 @@ impl Contract {
         let cli_contract = match variants[selected_contract] {
             ContractDiscriminants::ContractFile => CliContract::ContractFile(Default::default()),
         };
-        Ok(Self::from(
-            cli_contract,
-            connection_config,
-            sender_account_id,
-        )?)
+        Self::from(cli_contract, connection_config, sender_account_id)
     }
 
     pub async fn process(
@@ -142,7 +139,7 @@ impl ContractFile {
     ) -> color_eyre::eyre::Result<Self> {
         let file_path = match item.file_path {
             Some(cli_file_path) => cli_file_path,
-            None => ContractFile::input_file_path(),
+            None => ContractFile::input_file_path()?,
         };
         let next_action = match item.next_action {
             Some(cli_next_action) => self::initialize_mode::NextAction::from(
@@ -163,13 +160,14 @@ impl ContractFile {
 }
 
 impl ContractFile {
-    fn input_file_path() -> std::path::PathBuf {
+    fn input_file_path() -> color_eyre::eyre::Result<std::path::PathBuf> {
         println!();
+        crate::common::ensure_interactive("file-path")?;
         let input_file_path: String = Input::new()
             .with_prompt("What is a file location of the contract?")
             .interact_text()
             .unwrap();
-        input_file_path.into()
+        Ok(input_file_path.into())
     }
 
     pub async fn process(
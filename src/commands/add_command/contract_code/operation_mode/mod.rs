@@ -121,12 +121,16 @@ impl Mode {
 
 impl Mode {
     fn choose_mode() -> color_eyre::eyre::Result<Self> {
+        if crate::common::ConnectionConfig::preselected().is_some() {
+            return Self::from(CliMode::Network(Default::default()));
+        }
         println!();
         let variants = ModeDiscriminants::iter().collect::<Vec<_>>();
         let modes = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("mode")?;
         let selected_mode = Select::with_theme(&ColorfulTheme::default())
             .with_prompt(
                 "To construct a transaction you will need to provide information about sender (signer) and receiver accounts, and actions that needs to be performed.
@@ -140,7 +144,7 @@ impl Mode {
             ModeDiscriminants::Network => CliMode::Network(Default::default()),
             ModeDiscriminants::Offline => CliMode::Offline(Default::default()),
         };
-        Ok(Self::from(cli_mode)?)
+        Self::from(cli_mode)
     }
 
     pub async fn process(
@@ -86,6 +86,7 @@ impl Sender {
         connection_config: Option<crate::common::ConnectionConfig>,
     ) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
         loop {
+            crate::common::ensure_interactive("sender-account-id")?;
             let account_id: near_primitives::types::AccountId = Input::new()
                 .with_prompt("What is the account ID of the contract?")
                 .interact_text()
@@ -39,13 +39,13 @@ impl From<ImplicitAccount> for CliImplicitAccount {
     }
 }
 
-impl From<CliImplicitAccount> for ImplicitAccount {
-    fn from(item: CliImplicitAccount) -> Self {
+impl ImplicitAccount {
+    pub fn from(item: CliImplicitAccount) -> color_eyre::eyre::Result<Self> {
         let public_key_mode = match item.public_key_mode {
-            Some(cli_public_key_mode) => PublicKeyMode::from(cli_public_key_mode),
-            None => PublicKeyMode::choose_public_key_mode(),
+            Some(cli_public_key_mode) => PublicKeyMode::from(cli_public_key_mode)?,
+            None => PublicKeyMode::choose_public_key_mode()?,
         };
-        Self { public_key_mode }
+        Ok(Self { public_key_mode })
     }
 }
 
@@ -71,8 +71,8 @@ pub enum PublicKeyMode {
 impl CliPublicKeyMode {
     pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
         match self {
-            Self::GenerateKeypair(_) => {
-                let mut args = std::collections::VecDeque::new();
+            Self::GenerateKeypair(subcommand) => {
+                let mut args = subcommand.to_cli_args();
                 args.push_front("generate-keypair".to_owned());
                 args
             }
@@ -90,23 +90,24 @@ impl From<PublicKeyMode> for CliPublicKeyMode {
     }
 }
 
-impl From<CliPublicKeyMode> for PublicKeyMode {
-    fn from(item: CliPublicKeyMode) -> Self {
+impl PublicKeyMode {
+    pub fn from(item: CliPublicKeyMode) -> color_eyre::eyre::Result<Self> {
         match item {
             CliPublicKeyMode::GenerateKeypair(cli_generate_keypair) => {
-                PublicKeyMode::GenerateKeypair(cli_generate_keypair)
+                Ok(PublicKeyMode::GenerateKeypair(cli_generate_keypair))
             }
         }
     }
 }
 
 impl PublicKeyMode {
-    pub fn choose_public_key_mode() -> Self {
+    pub fn choose_public_key_mode() -> color_eyre::eyre::Result<Self> {
         let variants = PublicKeyModeDiscriminants::iter().collect::<Vec<_>>();
         let modes = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("public-key-mode")?;
         let select_mode = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Select a permission that you want to add to the access key:")
             .items(&modes)
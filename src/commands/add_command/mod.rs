@@ -142,18 +142,16 @@ impl Action {
                 self::access_key::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
             CliAction::ContractCode(cli_operation_mode) => Ok(Action::ContractCode(
-                self::contract_code::operation_mode::OperationMode::from(cli_operation_mode)
-                    .unwrap(),
+                self::contract_code::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliAction::ImplicitAccount(cli_generate_keypair) => Ok(Action::ImplicitAccount(
+                self::implicit_account::ImplicitAccount::from(cli_generate_keypair)?,
             )),
-            CliAction::ImplicitAccount(cli_generate_keypair) => {
-                Ok(Action::ImplicitAccount(cli_generate_keypair.into()))
-            }
             CliAction::StakeProposal(cli_operation_mode) => Ok(Action::StakeProposal(
-                self::stake_proposal::operation_mode::OperationMode::from(cli_operation_mode)
-                    .unwrap(),
+                self::stake_proposal::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
             CliAction::SubAccount(cli_operation_mode) => Ok(Action::SubAccount(
-                self::sub_account::operation_mode::OperationMode::from(cli_operation_mode).unwrap(),
+                self::sub_account::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
         }
     }
@@ -167,6 +165,7 @@ impl Action {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("action")?;
         let selected_action = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Сhoose what you want to add")
             .items(&actions)
@@ -180,7 +179,7 @@ impl Action {
             ActionDiscriminants::StakeProposal => CliAction::StakeProposal(Default::default()),
             ActionDiscriminants::SubAccount => CliAction::SubAccount(Default::default()),
         };
-        Ok(Self::from(cli_action)?)
+        Self::from(cli_action)
     }
 
     pub async fn process(
@@ -58,11 +58,11 @@ impl TransactionsSigning {
         connection_config: Option<crate::common::ConnectionConfig>,
         sender_account_id: near_primitives::types::AccountId,
     ) -> color_eyre::eyre::Result<Self> {
-        Ok(Self::from(
+        Self::from(
             CliTransactionsSigning::TransactionsSigningPublicKey(Default::default()),
             connection_config,
             sender_account_id,
-        )?)
+        )
     }
 
     pub async fn process(
@@ -141,7 +141,7 @@ impl TransactionsSigningAction {
         let transactions_signing_public_key: near_crypto::PublicKey =
             match item.transactions_signing_public_key {
                 Some(cli_transactions_signing_public_key) => cli_transactions_signing_public_key,
-                None => TransactionsSigningAction::input_public_key(),
+                None => TransactionsSigningAction::input_public_key()?,
             };
         let sign_option = match item.sign_option {
             Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(cli_sign_transaction, connection_config, sender_account_id)?,
@@ -155,11 +155,12 @@ impl TransactionsSigningAction {
 }
 
 impl TransactionsSigningAction {
-    fn input_public_key() -> near_crypto::PublicKey {
-        Input::new()
+    fn input_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        crate::common::ensure_interactive("public-key")?;
+        Ok(Input::new()
             .with_prompt("Enter a public key for this server")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
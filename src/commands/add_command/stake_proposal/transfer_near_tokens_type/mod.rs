@@ -56,11 +56,11 @@ impl Transfer {
         connection_config: Option<crate::common::ConnectionConfig>,
         sender_account_id: near_primitives::types::AccountId,
     ) -> color_eyre::eyre::Result<Self> {
-        Ok(Self::from(
+        Self::from(
             CliTransfer::Amount(Default::default()),
             connection_config,
             sender_account_id,
-        )?)
+        )
     }
 
     pub async fn process(
@@ -147,15 +147,15 @@ impl TransferNEARTokensAction {
                                 "You need to enter a value of no more than {}",
                                 account_balance
                             );
-                            TransferNEARTokensAction::input_amount(Some(account_balance))
+                            TransferNEARTokensAction::input_amount(Some(account_balance))?
                         }
                     }
-                    None => TransferNEARTokensAction::input_amount(Some(account_balance)),
+                    None => TransferNEARTokensAction::input_amount(Some(account_balance))?,
                 }
             }
             None => match item.amount {
                 Some(cli_amount) => cli_amount,
-                None => TransferNEARTokensAction::input_amount(None),
+                None => TransferNEARTokensAction::input_amount(None)?,
             },
         };
         let sign_transactions = match item.sign_transactions {
@@ -181,8 +181,9 @@ impl TransferNEARTokensAction {
 impl TransferNEARTokensAction {
     fn input_amount(
         account_balance: Option<crate::common::NearBalance>,
-    ) -> crate::common::NearBalance {
-        match account_balance {
+    ) -> color_eyre::eyre::Result<crate::common::NearBalance> {
+        crate::common::ensure_interactive("amount")?;
+        Ok(match account_balance {
             Some(account_balance) => loop {
                 let input_amount: crate::common::NearBalance = Input::new()
                             .with_prompt("How many NEAR Tokens do you want to transfer? (example: 10NEAR or 0.5near or 10000yoctonear)")
@@ -202,7 +203,7 @@ impl TransferNEARTokensAction {
                         .with_prompt("How many NEAR Tokens do you want to transfer? (example: 10NEAR or 0.5near or 10000yoctonear)")
                         .interact_text()
                         .unwrap()
-        }
+        })
     }
 
     pub async fn process(
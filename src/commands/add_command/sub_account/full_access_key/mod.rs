@@ -67,6 +67,7 @@ impl FullAccessKey {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("full-access-key")?;
         let selected_action = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Сhoose what you want to add")
             .items(&actions)
@@ -78,11 +79,7 @@ impl FullAccessKey {
                 CliFullAccessKey::SubAccountFullAccess(Default::default())
             }
         };
-        Ok(Self::from(
-            cli_action,
-            connection_config,
-            sender_account_id,
-        )?)
+        Self::from(cli_action, connection_config, sender_account_id)
     }
 
     pub async fn process(
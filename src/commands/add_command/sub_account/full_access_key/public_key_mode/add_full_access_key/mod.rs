@@ -56,7 +56,7 @@ impl AddAccessKeyAction {
     ) -> color_eyre::eyre::Result<Self> {
         let public_key: near_crypto::PublicKey = match item.public_key {
             Some(cli_public_key) => cli_public_key,
-            None => AddAccessKeyAction::input_public_key(),
+            None => AddAccessKeyAction::input_public_key()?,
         };
         let deposit = match item.deposit {
             Some(cli_deposit) => super::super::super::deposit::Deposit::from(
@@ -78,11 +78,12 @@ impl AddAccessKeyAction {
 }
 
 impl AddAccessKeyAction {
-    fn input_public_key() -> near_crypto::PublicKey {
-        Input::new()
+    fn input_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        crate::common::ensure_interactive("public-key")?;
+        Ok(Input::new()
             .with_prompt("Enter a public key for this access key")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -52,11 +52,11 @@ impl SendTo {
         connection_config: Option<crate::common::ConnectionConfig>,
         sender_account_id: near_primitives::types::AccountId,
     ) -> color_eyre::eyre::Result<Self> {
-        Ok(Self::from(
+        Self::from(
             CliSendTo::SubAccount(Default::default()),
             connection_config,
             sender_account_id,
-        )?)
+        )
     }
 
     pub async fn process(
@@ -124,7 +124,7 @@ impl SubAccount {
     ) -> color_eyre::eyre::Result<Self> {
         let sub_account_id: near_primitives::types::AccountId = match item.sub_account_id {
             Some(cli_sub_account_id) => cli_sub_account_id,
-            None => SubAccount::input_sub_account_id(),
+            None => SubAccount::input_sub_account_id()?,
         };
         let full_access_key = match item.full_access_key {
             Some(cli_full_access_key) => super::full_access_key::FullAccessKey::from(
@@ -145,11 +145,12 @@ impl SubAccount {
 }
 
 impl SubAccount {
-    fn input_sub_account_id() -> near_primitives::types::AccountId {
-        Input::new()
+    fn input_sub_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        crate::common::ensure_interactive("sub-account-id")?;
+        Ok(Input::new()
             .with_prompt("What is the sub-account ID?")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -83,6 +83,7 @@ impl Sender {
         connection_config: Option<crate::common::ConnectionConfig>,
     ) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
         loop {
+            crate::common::ensure_interactive("owner-account-id")?;
             let account_id: near_primitives::types::AccountId = Input::new()
                 .with_prompt("What is the owner account ID?")
                 .interact_text()
@@ -51,11 +51,11 @@ impl SendTo {
         connection_config: Option<crate::common::ConnectionConfig>,
         sender_account_id: near_primitives::types::AccountId,
     ) -> color_eyre::eyre::Result<Self> {
-        Ok(Self::from(
+        Self::from(
             CliSendTo::Receiver(Default::default()),
             connection_config,
             sender_account_id,
-        )?)
+        )
     }
 
     pub async fn process(
@@ -126,7 +126,7 @@ impl Receiver {
         let receiver_account_id: near_primitives::types::AccountId = match item.receiver_account_id
         {
             Some(cli_receiver_account_id) => cli_receiver_account_id,
-            None => Receiver::input_receiver_account_id(),
+            None => Receiver::input_receiver_account_id()?,
         };
         let action: super::transaction_actions::NextAction = match item.action {
             Some(cli_next_action) => super::transaction_actions::NextAction::from_cli_next_action(
@@ -147,11 +147,13 @@ impl Receiver {
 }
 
 impl Receiver {
-    pub fn input_receiver_account_id() -> near_primitives::types::AccountId {
-        Input::new()
+    pub fn input_receiver_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId>
+    {
+        crate::common::ensure_interactive("receiver-account-id")?;
+        Ok(Input::new()
             .with_prompt("What is the account ID of the receiver?")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
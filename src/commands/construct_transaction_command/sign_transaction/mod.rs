@@ -1,5 +1,4 @@
 use dialoguer::{theme::ColorfulTheme, Input, Select};
-use near_primitives::borsh::BorshSerialize;
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 mod sign_manually;
@@ -95,7 +94,7 @@ impl SignTransaction {
                 let private_key = self::sign_with_private_key::SignPrivateKey::from(
                     cli_private_key,
                     connection_config,
-                );
+                )?;
                 Ok(SignTransaction::SignPrivateKey(private_key))
             }
             CliSignTransaction::SignWithKeychain(cli_key_chain) => {
@@ -113,7 +112,7 @@ impl SignTransaction {
             }
             CliSignTransaction::SignManually(cli_manually) => {
                 let manually =
-                    self::sign_manually::SignManually::from(cli_manually, connection_config);
+                    self::sign_manually::SignManually::from(cli_manually, connection_config)?;
                 Ok(SignTransaction::SignManually(manually))
             }
         }
@@ -131,6 +130,7 @@ impl SignTransaction {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("sign-option")?;
         let select_sign_options = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Would you like to sign the transaction?")
             .items(&sign_options)
@@ -183,23 +183,23 @@ impl SignTransaction {
     }
 }
 
-fn input_signer_public_key() -> near_crypto::PublicKey {
-    Input::new()
+fn input_signer_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+    crate::common::ensure_interactive("signer-public-key")?;
+    Ok(Input::new()
         .with_prompt("To create an unsigned transaction enter sender's public key")
         .interact_text()
-        .unwrap()
+        .unwrap())
 }
 
-fn input_signer_private_key() -> near_crypto::SecretKey {
-    Input::new()
-        .with_prompt("Enter sender's private key")
-        .interact_text()
-        .unwrap()
+fn input_signer_private_key() -> color_eyre::eyre::Result<near_crypto::SecretKey> {
+    crate::common::ensure_interactive("signer-private-key")?;
+    Ok(crate::common::input_secret("Enter sender's private key"))
 }
 
-fn input_access_key_nonce(public_key: &str) -> u64 {
+fn input_access_key_nonce(public_key: &str) -> color_eyre::eyre::Result<u64> {
     println!("Your public key: `{}`", public_key);
-    Input::new()
+    crate::common::ensure_interactive("access-key-nonce")?;
+    Ok(Input::new()
         .with_prompt(
             "Enter transaction nonce for this public key (query the access key information with \
             `./near-cli view nonce \
@@ -208,10 +208,11 @@ fn input_access_key_nonce(public_key: &str) -> u64 {
                 public-key ed25519:...` incremented by 1)",
         )
         .interact_text()
-        .unwrap()
+        .unwrap())
 }
 
-fn input_block_hash() -> near_primitives::hash::CryptoHash {
+fn input_block_hash() -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+    crate::common::ensure_interactive("block-hash")?;
     let input_block_hash: crate::common::BlockHashAsBase58 = Input::new()
         .with_prompt(
             "Enter recent block hash (query information about the hash of the last block with \
@@ -219,7 +220,7 @@ fn input_block_hash() -> near_primitives::hash::CryptoHash {
         )
         .interact_text()
         .unwrap();
-    input_block_hash.inner
+    Ok(input_block_hash.inner)
 }
 
 #[derive(Debug, EnumDiscriminants, Clone, clap::Clap)]
@@ -249,7 +250,9 @@ impl Submit {
         }
     }
 
-    pub fn choose_submit(connection_config: Option<crate::common::ConnectionConfig>) -> Self {
+    pub fn choose_submit(
+        connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = SubmitDiscriminants::iter().collect::<Vec<_>>();
 
@@ -261,22 +264,27 @@ impl Submit {
         } else {
             vec!["Do you want show the transaction on display?".to_string()]
         };
+        crate::common::ensure_interactive("submit")?;
         let select_submit = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Select an action that you want to add to the action:")
             .items(&submits)
             .default(0)
             .interact()
             .unwrap();
-        match variants[select_submit] {
+        Ok(match variants[select_submit] {
             SubmitDiscriminants::Send => Submit::Send,
             SubmitDiscriminants::Display => Submit::Display,
-        }
+        })
     }
 
     pub fn process_offline(
         self,
         serialize_to_base64: String,
     ) -> color_eyre::eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
+        crate::common::add_json_output(
+            "signed_transaction_base64",
+            serde_json::json!(serialize_to_base64),
+        );
         println!("Serialize_to_base64:\n{}", &serialize_to_base64);
         Ok(None)
     }
@@ -290,36 +298,20 @@ impl Submit {
         match self {
             Submit::Send => {
                 println!("Transaction sent ...");
-                let json_rcp_client =
-                    near_jsonrpc_client::new_client(network_connection_config.rpc_url().as_str());
-                let transaction_info = loop {
-                    let transaction_info_result = json_rcp_client
-                        .broadcast_tx_commit(near_primitives::serialize::to_base64(
-                            signed_transaction
-                                .try_to_vec()
-                                .expect("Transaction is not expected to fail on serialization"),
-                        ))
-                        .await;
-                    match transaction_info_result {
-                        Ok(response) => {
-                            break response;
-                        }
-                        Err(err) => {
-                            if let Some(serde_json::Value::String(data)) = &err.data {
-                                if data.contains("Timeout") {
-                                    println!("Timeout error transaction.\nPlease wait. The next try to send this transaction is happening right now ...");
-                                    continue;
-                                } else {
-                                    println!("Error transaction: {:#?}", err)
-                                }
-                            };
-                            return Ok(None);
-                        }
-                    };
-                };
+                let transaction_info = network_connection_config
+                    .rpc_client()
+                    .broadcast_tx_commit(&signed_transaction)
+                    .await
+                    .map_err(|err| {
+                        color_eyre::Report::msg(format!("Error transaction: {:?}", err))
+                    })?;
                 Ok(Some(transaction_info))
             }
             Submit::Display => {
+                crate::common::add_json_output(
+                    "signed_transaction_base64",
+                    serde_json::json!(serialize_to_base64),
+                );
                 println!("\nSerialize_to_base64:\n{}", &serialize_to_base64);
                 Ok(None)
             }
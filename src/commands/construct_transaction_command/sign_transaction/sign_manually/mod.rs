@@ -56,41 +56,37 @@ impl SignManually {
     pub fn from(
         item: CliSignManually,
         connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> Self {
+    ) -> color_eyre::eyre::Result<Self> {
         let signer_public_key: near_crypto::PublicKey = match item.signer_public_key {
             Some(cli_public_key) => cli_public_key,
-            None => super::input_signer_public_key(),
+            None => super::input_signer_public_key()?,
         };
         match connection_config {
-            Some(_) => Self {
+            Some(_) => Ok(Self {
                 signer_public_key,
                 nonce: None,
                 block_hash: None,
-            },
+            }),
             None => {
                 let nonce: u64 = match item.nonce {
                     Some(cli_nonce) => cli_nonce,
-                    None => super::input_access_key_nonce(&signer_public_key.to_string()),
+                    None => super::input_access_key_nonce(&signer_public_key.to_string())?,
                 };
                 let block_hash = match item.block_hash {
                     Some(cli_block_hash) => cli_block_hash,
-                    None => super::input_block_hash(),
+                    None => super::input_block_hash()?,
                 };
-                Self {
+                Ok(Self {
                     signer_public_key,
                     nonce: Some(nonce),
                     block_hash: Some(block_hash),
-                }
+                })
             }
         }
     }
 }
 
 impl SignManually {
-    fn rpc_client(self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
-    }
-
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -106,8 +102,8 @@ impl SignManually {
                 ..prepopulated_unsigned_transaction
             },
             Some(network_connection_config) => {
-                let online_signer_access_key_response = self
-                    .rpc_client(network_connection_config.rpc_url().as_str())
+                let online_signer_access_key_response = network_connection_config
+                    .rpc_client()
                     .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
                         block_reference: near_primitives::types::Finality::Final.into(),
                         request: near_primitives::views::QueryRequest::ViewAccessKey {
@@ -148,6 +144,10 @@ impl SignManually {
                 .try_to_vec()
                 .expect("Transaction is not expected to fail on serialization"),
         );
+        crate::common::add_json_output(
+            "unsigned_transaction_base64",
+            serde_json::json!(serialize_to_base64),
+        );
         println!("\nSerialize_to_base64:\n{}", &serialize_to_base64);
         Ok(None)
     }
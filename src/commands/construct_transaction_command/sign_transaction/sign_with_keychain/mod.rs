@@ -1,5 +1,6 @@
 extern crate dirs;
 
+use dialoguer::{theme::ColorfulTheme, Select};
 use serde::Deserialize;
 
 /// подписание сформированной транзакции с помощью файла с ключами
@@ -10,6 +11,9 @@ use serde::Deserialize;
     setting(clap::AppSettings::VersionlessSubcommands)
 )]
 pub struct CliSignKeychain {
+    /// публичный ключ, которым подписать транзакцию, если в хранилище несколько ключей аккаунта
+    #[clap(long)]
+    signer_public_key: Option<near_crypto::PublicKey>,
     #[clap(long)]
     nonce: Option<u64>,
     #[clap(long)]
@@ -20,6 +24,7 @@ pub struct CliSignKeychain {
 
 #[derive(Debug, Clone)]
 pub struct SignKeychain {
+    signer_public_key: Option<near_crypto::PublicKey>,
     nonce: Option<u64>,
     block_hash: Option<near_primitives::hash::CryptoHash>,
     pub submit: Option<super::Submit>,
@@ -40,6 +45,10 @@ impl CliSignKeychain {
             args.push_front(block_hash.to_string());
             args.push_front("--block-hash".to_owned())
         }
+        if let Some(signer_public_key) = &self.signer_public_key {
+            args.push_front(signer_public_key.to_string());
+            args.push_front("--signer-public-key".to_owned())
+        }
         args
     }
 }
@@ -47,6 +56,7 @@ impl CliSignKeychain {
 impl From<SignKeychain> for CliSignKeychain {
     fn from(sign_keychain: SignKeychain) -> Self {
         Self {
+            signer_public_key: sign_keychain.signer_public_key,
             nonce: sign_keychain.nonce,
             block_hash: sign_keychain.block_hash,
             submit: sign_keychain.submit,
@@ -63,21 +73,33 @@ impl SignKeychain {
         let submit: Option<super::Submit> = item.submit;
         match connection_config {
             Some(_) => Ok(Self {
+                signer_public_key: item.signer_public_key,
                 nonce: None,
                 block_hash: None,
                 submit,
             }),
             None => {
-                let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
-                let file_name = format!("{}.json", sender_account_id);
-                let mut path = std::path::PathBuf::from(&home_dir);
-                let dir_name = crate::consts::DIR_NAME_KEY_CHAIN;
-                path.push(dir_name);
-                path.push(file_name);
-                let data = std::fs::read_to_string(path).map_err(|err| {
-                    color_eyre::Report::msg(format!("Access key file not found! Error: {}", err))
-                })?;
-                let account_json: User = serde_json::from_str(&data).map_err(|err| {
+                let keys = crate::keychain::find_account_keys(
+                    &crate::keychain::credentials_search_path(None)?,
+                    &sender_account_id.to_string(),
+                )?;
+                let key = match &item.signer_public_key {
+                    Some(signer_public_key) => keys
+                        .into_iter()
+                        .find(|key| key.public_key == signer_public_key.to_string())
+                        .ok_or_else(|| {
+                            color_eyre::Report::msg(format!(
+                                "The key {} of <{}> is not found in the keychain",
+                                signer_public_key, sender_account_id
+                            ))
+                        })?,
+                    // Without the network the keys cannot be checked on-chain
+                    None => choose_key(keys, &sender_account_id)?,
+                };
+                let account_json: User = serde_json::from_value(crate::keychain::read_key_file(
+                    &key.path,
+                )?)
+                .map_err(|err| {
                     color_eyre::Report::msg(format!(
                         "Data for the access key was not found in the file! Error: {}",
                         err
@@ -86,13 +108,14 @@ impl SignKeychain {
 
                 let nonce: u64 = match item.nonce {
                     Some(cli_nonce) => cli_nonce,
-                    None => super::input_access_key_nonce(&account_json.public_key.to_string()),
+                    None => super::input_access_key_nonce(&account_json.public_key()?.to_string())?,
                 };
                 let block_hash = match item.block_hash {
                     Some(cli_block_hash) => cli_block_hash,
-                    None => super::input_block_hash(),
+                    None => super::input_block_hash()?,
                 };
                 Ok(SignKeychain {
+                    signer_public_key: Some(account_json.public_key()?),
                     nonce: Some(nonce),
                     block_hash: Some(block_hash),
                     submit,
@@ -102,48 +125,168 @@ impl SignKeychain {
     }
 }
 
+/// The only key, or the one picked in a prompt when there are several.
+fn choose_key(
+    mut keys: Vec<crate::keychain::KeychainKey>,
+    signer_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<crate::keychain::KeychainKey> {
+    match keys.len() {
+        0 => Err(no_keys_error(signer_id)),
+        1 => Ok(keys.remove(0)),
+        _ => {
+            crate::common::ensure_interactive("signer-public-key")?;
+            let selection = Select::with_theme(&ColorfulTheme::default())
+                .with_prompt(format!(
+                    "Several keys of <{}> can sign the transaction. Which one to use?",
+                    signer_id
+                ))
+                .items(
+                    &keys
+                        .iter()
+                        .map(|key| key.public_key.as_str())
+                        .collect::<Vec<_>>(),
+                )
+                .default(0)
+                .interact()?;
+            Ok(keys.swap_remove(selection))
+        }
+    }
+}
+
+fn no_keys_error(signer_id: &near_primitives::types::AccountId) -> color_eyre::Report {
+    color_eyre::Report::msg(format!(
+        "There are no access keys found in the keychain for the signer account <{}>. Log in before signing transactions with keychain.",
+        signer_id
+    ))
+}
+
+/// Whether the access key may sign the transaction: a full access key signs anything, a function
+/// call key only calls of its methods on its receiver that attach no deposit.
+fn is_allowed_to_sign(
+    permission: &near_primitives::views::AccessKeyPermissionView,
+    transaction: &near_primitives::transaction::Transaction,
+) -> bool {
+    match permission {
+        near_primitives::views::AccessKeyPermissionView::FullAccess => true,
+        near_primitives::views::AccessKeyPermissionView::FunctionCall {
+            allowance: _,
+            receiver_id,
+            method_names,
+        } => {
+            receiver_id == &transaction.receiver_id.to_string()
+                && !transaction.actions.is_empty()
+                && transaction.actions.iter().all(|action| match action {
+                    near_primitives::transaction::Action::FunctionCall(function_call_action) => {
+                        function_call_action.deposit == 0
+                            && (method_names.is_empty()
+                                || method_names.contains(&function_call_action.method_name))
+                    }
+                    _ => false,
+                })
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct User {
     account_id: near_primitives::types::AccountId,
-    public_key: near_crypto::PublicKey,
-    private_key: near_crypto::SecretKey,
+    // Older key files of the JavaScript near-cli have only the private key
+    #[serde(default)]
+    public_key: Option<near_crypto::PublicKey>,
+    // `validator_key.json` of a local node names this field `secret_key`; a Ledger key has none
+    #[serde(default, alias = "secret_key")]
+    private_key: Option<near_crypto::SecretKey>,
+    #[serde(default)]
+    seed_phrase_hd_path: Option<String>,
+    /// The key is kept on a Ledger device under `seed_phrase_hd_path`
+    #[serde(default)]
+    ledger: bool,
 }
 
-impl SignKeychain {
-    fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
+impl User {
+    fn public_key(&self) -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        match (&self.public_key, &self.private_key) {
+            (Some(public_key), _) => Ok(public_key.clone()),
+            (None, Some(private_key)) => Ok(private_key.public_key()),
+            (None, None) => Err(color_eyre::Report::msg(format!(
+                "The key file of <{}> has neither a public nor a private key",
+                self.account_id
+            ))),
+        }
+    }
+
+    fn ledger_seed_phrase_hd_path(&self) -> color_eyre::eyre::Result<slip10::BIP32Path> {
+        let seed_phrase_hd_path = self.seed_phrase_hd_path.as_deref().ok_or_else(|| {
+            color_eyre::Report::msg(format!(
+                "The Ledger key file of <{}> has no seed phrase HD path",
+                self.account_id
+            ))
+        })?;
+        seed_phrase_hd_path.parse().map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Invalid seed phrase HD path {}: {:?}",
+                seed_phrase_hd_path, err
+            ))
+        })
     }
+}
 
+/// Returns the `validator_key.json` of a local node if it holds the key of the signer account.
+fn validator_key_path(
+    network_connection_config: &crate::common::ConnectionConfig,
+    signer_id: &near_primitives::types::AccountId,
+) -> Option<std::path::PathBuf> {
+    let validator_key_path = network_connection_config.validator_key_path()?;
+    let data = std::fs::read_to_string(&validator_key_path).ok()?;
+    let validator_key: User = serde_json::from_str(&data).ok()?;
+    if &validator_key.account_id == signer_id {
+        Some(validator_key_path)
+    } else {
+        None
+    }
+}
+
+impl SignKeychain {
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         connection_config: Option<crate::common::ConnectionConfig>,
     ) -> color_eyre::eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
-        let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
-        let file_name = format!("{}.json", prepopulated_unsigned_transaction.signer_id);
-        let mut path = std::path::PathBuf::from(&home_dir);
+        let signer_id = &prepopulated_unsigned_transaction.signer_id;
+        let keys = crate::keychain::find_account_keys(
+            &crate::keychain::credentials_search_path(connection_config.as_ref())?,
+            &signer_id.to_string(),
+        )?;
 
-        let data_path: std::path::PathBuf = match &connection_config {
-            None => {
-                let dir_name = crate::consts::DIR_NAME_KEY_CHAIN;
-                path.push(dir_name);
-                path.push(file_name);
-                path
+        let data_path: std::path::PathBuf = match (&self.signer_public_key, &connection_config) {
+            (Some(signer_public_key), _) => {
+                keys.into_iter()
+                    .find(|key| key.public_key == signer_public_key.to_string())
+                    .ok_or_else(|| {
+                        color_eyre::Report::msg(format!(
+                            "The key {} of <{}> is not found in the keychain",
+                            signer_public_key, signer_id
+                        ))
+                    })?
+                    .path
             }
-            Some(network_connection_config) => {
-                let dir_name = network_connection_config.dir_name();
-                path.push(dir_name);
-                path.push(file_name);
-
-                if path.exists() {
-                    path
+            (None, None) => choose_key(keys, signer_id)?.path,
+            (None, Some(network_connection_config)) => {
+                if keys.is_empty() {
+                    if let Some(validator_key_path) =
+                        validator_key_path(network_connection_config, signer_id)
+                    {
+                        validator_key_path
+                    } else {
+                        return Err(no_keys_error(signer_id));
+                    }
                 } else {
-                    let query_view_method_response = self
-                        .rpc_client(network_connection_config.rpc_url().as_str())
+                    let query_view_method_response = network_connection_config
+                        .rpc_client()
                         .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
                             block_reference: near_primitives::types::Finality::Final.into(),
                             request: near_primitives::views::QueryRequest::ViewAccessKeyList {
-                                account_id: prepopulated_unsigned_transaction.signer_id.clone(),
+                                account_id: signer_id.clone(),
                             },
                         })
                         .await
@@ -162,64 +305,207 @@ impl SignKeychain {
                         } else {
                             return Err(color_eyre::Report::msg(format!("Error call result")));
                         };
-                    let mut path = std::path::PathBuf::from(&home_dir);
-                    path.push(dir_name);
-                    path.push(&prepopulated_unsigned_transaction.signer_id.to_string());
-                    let mut data_path = std::path::PathBuf::new();
-                    'outer: for access_key in access_key_view.keys {
-                        let account_public_key = access_key.public_key.to_string();
-                        let is_full_access_key: bool = match &access_key.access_key.permission {
-                            near_primitives::views::AccessKeyPermissionView::FullAccess => true,
-                            near_primitives::views::AccessKeyPermissionView::FunctionCall {
-                                allowance: _,
-                                receiver_id: _,
-                                method_names: _,
-                            } => false,
-                        };
-                        let dir = path
-                            .read_dir()
-                            .map_err(|err| {
-                                color_eyre::Report::msg(format!("There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain. {}", err))
-                            })?;
-                        for entry in dir {
-                            if let Ok(entry) = entry {
-                                if entry
-                                    .path()
-                                    .file_stem()
-                                    .unwrap()
-                                    .to_str()
-                                    .unwrap()
-                                    .contains(account_public_key.rsplit(':').next().unwrap())
-                                    && is_full_access_key
-                                {
-                                    data_path.push(entry.path());
-                                    break 'outer;
-                                }
-                            } else {
-                                return Err(color_eyre::Report::msg(format!(
-                                    "There are no access keys found in the keychain for the signer account. Log in before signing transactions with keychain."
-                                )));
-                            };
-                        }
+                    let allowed_keys = keys
+                        .into_iter()
+                        .filter(|key| {
+                            access_key_view.keys.iter().any(|access_key| {
+                                access_key.public_key.to_string() == key.public_key
+                                    && is_allowed_to_sign(
+                                        &access_key.access_key.permission,
+                                        &prepopulated_unsigned_transaction,
+                                    )
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    if allowed_keys.is_empty() {
+                        return Err(color_eyre::Report::msg(format!(
+                            "None of the keys of <{}> in the keychain is an access key that can sign this transaction",
+                            signer_id
+                        )));
                     }
-                    data_path
+                    choose_key(allowed_keys, signer_id)?.path
                 }
             }
         };
-        let data = std::fs::read_to_string(data_path).map_err(|err| {
-            color_eyre::Report::msg(format!("Access key file not found! Error: {}", err))
+        let account_json: User =
+            serde_json::from_value(crate::keychain::read_key_file(&data_path)?)
+                .map_err(|err| color_eyre::Report::msg(format!("Error reading data: {}", err)))?;
+        match self.signer(account_json, &data_path)? {
+            KeychainSigner::Ledger(sign_with_ledger) => {
+                sign_with_ledger
+                    .process(prepopulated_unsigned_transaction, connection_config)
+                    .await
+            }
+            KeychainSigner::PrivateKey(sign_with_private_key) => {
+                sign_with_private_key
+                    .process(prepopulated_unsigned_transaction, connection_config)
+                    .await
+            }
+        }
+    }
+
+    /// A Ledger key file is signed with on the device, any other with its private key.
+    fn signer(
+        self,
+        account_json: User,
+        data_path: &std::path::Path,
+    ) -> color_eyre::eyre::Result<KeychainSigner> {
+        if account_json.ledger {
+            return Ok(KeychainSigner::Ledger(
+                super::sign_with_ledger::SignLedger {
+                    seed_phrase_hd_path: account_json.ledger_seed_phrase_hd_path()?,
+                    signer_public_key: account_json.public_key()?,
+                    nonce: self.nonce,
+                    block_hash: self.block_hash,
+                    submit: self.submit,
+                },
+            ));
+        }
+        let signer_private_key = account_json.private_key.clone().ok_or_else(|| {
+            color_eyre::Report::msg(format!(
+                "The key file {} has no private key",
+                data_path.display()
+            ))
         })?;
-        let account_json: User = serde_json::from_str(&data)
-            .map_err(|err| color_eyre::Report::msg(format!("Error reading data: {}", err)))?;
-        let sign_with_private_key = super::sign_with_private_key::SignPrivateKey {
-            signer_public_key: account_json.public_key,
-            signer_private_key: account_json.private_key,
-            nonce: self.nonce.clone(),
-            block_hash: self.block_hash.clone(),
-            submit: self.submit.clone(),
+        Ok(KeychainSigner::PrivateKey(
+            super::sign_with_private_key::SignPrivateKey {
+                signer_public_key: account_json.public_key()?,
+                signer_private_key: signer_private_key.into(),
+                nonce: self.nonce,
+                block_hash: self.block_hash,
+                submit: self.submit,
+            },
+        ))
+    }
+}
+
+enum KeychainSigner {
+    Ledger(super::sign_with_ledger::SignLedger),
+    PrivateKey(super::sign_with_private_key::SignPrivateKey),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn function_call_key_is_allowed_to_sign_its_calls_only() {
+        let transaction = |actions| near_primitives::transaction::Transaction {
+            signer_id: "volodymyr.testnet".parse().unwrap(),
+            public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+            nonce: 0,
+            receiver_id: "contract.testnet".parse().unwrap(),
+            block_hash: Default::default(),
+            actions,
+        };
+        let function_call = |method_name: &str, deposit| {
+            near_primitives::transaction::Action::FunctionCall(
+                near_primitives::transaction::FunctionCallAction {
+                    method_name: method_name.to_owned(),
+                    args: vec![],
+                    gas: 1,
+                    deposit,
+                },
+            )
+        };
+        let function_call_key = near_primitives::views::AccessKeyPermissionView::FunctionCall {
+            allowance: None,
+            receiver_id: "contract.testnet".to_owned(),
+            method_names: vec!["increment".to_owned()],
+        };
+        assert!(is_allowed_to_sign(
+            &function_call_key,
+            &transaction(vec![function_call("increment", 0)])
+        ));
+        assert!(!is_allowed_to_sign(
+            &function_call_key,
+            &transaction(vec![function_call("decrement", 0)])
+        ));
+        assert!(!is_allowed_to_sign(
+            &function_call_key,
+            &transaction(vec![function_call("increment", 1)])
+        ));
+        assert!(!is_allowed_to_sign(
+            &function_call_key,
+            &transaction(vec![near_primitives::transaction::Action::Transfer(
+                near_primitives::transaction::TransferAction { deposit: 1 }
+            )])
+        ));
+        assert!(is_allowed_to_sign(
+            &near_primitives::views::AccessKeyPermissionView::FullAccess,
+            &transaction(vec![near_primitives::transaction::Action::Transfer(
+                near_primitives::transaction::TransferAction { deposit: 1 }
+            )])
+        ));
+    }
+
+    #[test]
+    fn ledger_key_file_has_no_private_key() {
+        let user: User = serde_json::from_value(serde_json::json!({
+            "account_id": "volodymyr.testnet",
+            "public_key": "ed25519:6j4b6zUaty6fD1awqcGCCU9JYGCWYUgdJhQrzfZhqE25",
+            "seed_phrase_hd_path": "44'/397'/0'/0'/1'",
+            "ledger": true,
+        }))
+        .unwrap();
+        assert!(user.ledger);
+        assert!(user.private_key.is_none());
+        assert_eq!(
+            user.public_key().unwrap().to_string(),
+            "ed25519:6j4b6zUaty6fD1awqcGCCU9JYGCWYUgdJhQrzfZhqE25"
+        );
+        assert_eq!(
+            user.ledger_seed_phrase_hd_path().unwrap().to_string(),
+            "44'/397'/0'/0'/1'"
+                .parse::<slip10::BIP32Path>()
+                .unwrap()
+                .to_string()
+        );
+
+        let user: User = serde_json::from_value(serde_json::json!({
+            "account_id": "volodymyr.testnet",
+            "secret_key": "ed25519:3UxxZHyZRoFGcnjEdBVSBNRGEJHU6Xt8cx3gN5s5H4YiQpbjXVR7Lq6NFAsPhpHtuNs5ghJxeRdHizqBFCSkDvDr",
+        }))
+        .unwrap();
+        assert!(!user.ledger);
+        assert!(user.public_key().is_ok());
+    }
+
+    #[test]
+    fn ledger_key_file_is_signed_with_on_the_ledger_device() {
+        let public_key: near_crypto::PublicKey =
+            "ed25519:6j4b6zUaty6fD1awqcGCCU9JYGCWYUgdJhQrzfZhqE25"
+                .parse()
+                .unwrap();
+        let seed_phrase_hd_path: slip10::BIP32Path = "44'/397'/0'/0'/1'".parse().unwrap();
+        let key_file = crate::commands::login::operation_mode::ledger_mode::ledger_key_file(
+            "volodymyr.testnet",
+            &public_key,
+            &seed_phrase_hd_path,
+        );
+        let sign_keychain = SignKeychain {
+            signer_public_key: None,
+            nonce: None,
+            block_hash: None,
+            submit: None,
         };
-        sign_with_private_key
-            .process(prepopulated_unsigned_transaction, connection_config)
-            .await
+        match sign_keychain
+            .signer(
+                serde_json::from_value(key_file).unwrap(),
+                std::path::Path::new("volodymyr.testnet.json"),
+            )
+            .unwrap()
+        {
+            KeychainSigner::Ledger(sign_with_ledger) => {
+                assert_eq!(sign_with_ledger.signer_public_key, public_key);
+                assert_eq!(
+                    sign_with_ledger.seed_phrase_hd_path.to_string(),
+                    seed_phrase_hd_path.to_string()
+                );
+            }
+            KeychainSigner::PrivateKey(_) => {
+                panic!("The Ledger key file is signed with a private key")
+            }
+        }
     }
 }
@@ -23,8 +23,8 @@ pub struct CliSignLedger {
 pub struct SignLedger {
     pub seed_phrase_hd_path: slip10::BIP32Path,
     pub signer_public_key: near_crypto::PublicKey,
-    nonce: Option<u64>,
-    block_hash: Option<near_primitives::hash::CryptoHash>,
+    pub nonce: Option<u64>,
+    pub block_hash: Option<near_primitives::hash::CryptoHash>,
     pub submit: Option<super::Submit>,
 }
 
@@ -65,7 +65,7 @@ impl SignLedger {
     ) -> color_eyre::eyre::Result<Self> {
         let seed_phrase_hd_path = match item.seed_phrase_hd_path {
             Some(hd_path) => hd_path,
-            None => SignLedger::input_seed_phrase_hd_path(),
+            None => SignLedger::input_seed_phrase_hd_path()?,
         };
         println!(
             "Please allow getting the PublicKey on Ledger device (HD Path: {})",
@@ -94,11 +94,11 @@ impl SignLedger {
             None => {
                 let nonce: u64 = match item.nonce {
                     Some(cli_nonce) => cli_nonce,
-                    None => super::input_access_key_nonce(&signer_public_key.to_string().clone()),
+                    None => super::input_access_key_nonce(&signer_public_key.to_string().clone())?,
                 };
                 let block_hash = match item.block_hash {
                     Some(cli_block_hash) => cli_block_hash,
-                    None => super::input_block_hash(),
+                    None => super::input_block_hash()?,
                 };
                 Ok(Self {
                     seed_phrase_hd_path,
@@ -113,16 +113,13 @@ impl SignLedger {
 }
 
 impl SignLedger {
-    fn rpc_client(self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
-    }
-
-    pub fn input_seed_phrase_hd_path() -> slip10::BIP32Path {
-        Input::new()
+    pub fn input_seed_phrase_hd_path() -> color_eyre::eyre::Result<slip10::BIP32Path> {
+        crate::common::ensure_interactive("seed-phrase-hd-path")?;
+        Ok(Input::new()
             .with_prompt("Enter seed phrase HD Path (if you not sure leave blank for default)")
             .with_initial_text("44'/397'/0'/0'/1'")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -183,14 +180,14 @@ impl SignLedger {
                 match submit {
                     Some(submit) => submit.process_offline(serialize_to_base64),
                     None => {
-                        let submit = super::Submit::choose_submit(connection_config.clone());
+                        let submit = super::Submit::choose_submit(connection_config.clone())?;
                         submit.process_offline(serialize_to_base64)
                     }
                 }
             }
             Some(network_connection_config) => {
-                let online_signer_access_key_response = self
-                    .rpc_client(network_connection_config.rpc_url().as_str())
+                let online_signer_access_key_response = network_connection_config
+                    .rpc_client()
                     .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
                         block_reference: near_primitives::types::Finality::Final.into(),
                         request: near_primitives::views::QueryRequest::ViewAccessKey {
@@ -259,7 +256,7 @@ impl SignLedger {
                 println!("Your transaction was signed successfully.");
                 match submit {
                     None => {
-                        let submit = super::Submit::choose_submit(connection_config);
+                        let submit = super::Submit::choose_submit(connection_config)?;
                         submit
                             .process_online(
                                 network_connection_config,
@@ -10,8 +10,9 @@ use near_primitives::borsh::BorshSerialize;
 pub struct CliSignPrivateKey {
     #[clap(long)]
     signer_public_key: Option<near_crypto::PublicKey>,
+    /// the private key itself, @path/to/file, - (stdin) or env:VAR_NAME
     #[clap(long)]
-    signer_private_key: Option<near_crypto::SecretKey>,
+    signer_private_key: Option<crate::common::SecretArg<near_crypto::SecretKey>>,
     #[clap(long)]
     nonce: Option<u64>,
     #[clap(long)]
@@ -23,7 +24,7 @@ pub struct CliSignPrivateKey {
 #[derive(Debug, Clone)]
 pub struct SignPrivateKey {
     pub signer_public_key: near_crypto::PublicKey,
-    pub signer_private_key: near_crypto::SecretKey,
+    pub signer_private_key: crate::common::SecretArg<near_crypto::SecretKey>,
     pub nonce: Option<u64>,
     pub block_hash: Option<near_primitives::hash::CryptoHash>,
     pub submit: Option<super::Submit>,
@@ -44,8 +45,8 @@ impl CliSignPrivateKey {
             args.push_front(nonce.to_string());
             args.push_front("--nonce".to_owned())
         }
-        if let Some(signer_secret_key) = &self.signer_private_key {
-            args.push_front(signer_secret_key.to_string());
+        if let Some(signer_private_key) = &self.signer_private_key {
+            args.push_front(signer_private_key.to_string());
             args.push_front("--signer-private-key".to_owned())
         }
         if let Some(signer_public_key) = &self.signer_public_key {
@@ -72,53 +73,53 @@ impl SignPrivateKey {
     pub fn from(
         item: CliSignPrivateKey,
         connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> Self {
+    ) -> color_eyre::eyre::Result<Self> {
         let signer_public_key: near_crypto::PublicKey = match item.signer_public_key {
             Some(cli_public_key) => cli_public_key,
-            None => super::input_signer_public_key(),
+            None => super::input_signer_public_key()?,
         };
-        let signer_private_key: near_crypto::SecretKey = match item.signer_private_key {
+        let signer_private_key = match item.signer_private_key {
             Some(signer_private_key) => signer_private_key,
-            None => super::input_signer_private_key(),
+            None => super::input_signer_private_key()?.into(),
         };
         let submit: Option<super::Submit> = item.submit;
         match connection_config {
-            Some(_) => Self {
+            Some(_) => Ok(Self {
                 signer_public_key,
                 signer_private_key,
                 nonce: None,
                 block_hash: None,
                 submit,
-            },
+            }),
             None => {
                 let nonce: u64 = match item.nonce {
                     Some(cli_nonce) => cli_nonce,
-                    None => super::input_access_key_nonce(&signer_public_key.to_string()),
+                    None => super::input_access_key_nonce(&signer_public_key.to_string())?,
                 };
                 let block_hash = match item.block_hash {
                     Some(cli_block_hash) => cli_block_hash,
-                    None => super::input_block_hash(),
+                    None => super::input_block_hash()?,
                 };
                 let public_key_origin: near_crypto::PublicKey =
-                    near_crypto::SecretKey::public_key(&signer_private_key);
+                    near_crypto::SecretKey::public_key(&signer_private_key.inner);
                 if &signer_public_key == &public_key_origin {
-                    Self {
+                    Ok(Self {
                         signer_public_key,
                         signer_private_key,
                         nonce: Some(nonce),
                         block_hash: Some(block_hash),
                         submit,
-                    }
+                    })
                 } else {
                     println!("\nError: The key pair does not match. Re-enter the keys.\n");
                     let signer_public_key: near_crypto::PublicKey =
-                        super::input_signer_public_key();
+                        super::input_signer_public_key()?;
                     let signer_secret_key: near_crypto::SecretKey =
-                        super::input_signer_private_key();
+                        super::input_signer_private_key()?;
                     Self::from(
                         CliSignPrivateKey {
                             signer_public_key: Some(signer_public_key),
-                            signer_private_key: Some(signer_secret_key),
+                            signer_private_key: Some(signer_secret_key.into()),
                             nonce: Some(nonce),
                             block_hash: Some(block_hash),
                             submit: None,
@@ -132,17 +133,13 @@ impl SignPrivateKey {
 }
 
 impl SignPrivateKey {
-    fn rpc_client(self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
-    }
-
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         connection_config: Option<crate::common::ConnectionConfig>,
     ) -> color_eyre::eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
         let public_key: near_crypto::PublicKey = self.signer_public_key.clone();
-        let signer_secret_key: near_crypto::SecretKey = self.signer_private_key.clone();
+        let signer_secret_key: near_crypto::SecretKey = self.signer_private_key.inner.clone();
         let nonce: u64 = self.nonce.unwrap_or_default().clone();
         let block_hash: near_primitives::hash::CryptoHash =
             self.block_hash.unwrap_or_default().clone();
@@ -172,14 +169,14 @@ impl SignPrivateKey {
                 match submit {
                     Some(submit) => submit.process_offline(serialize_to_base64),
                     None => {
-                        let submit = super::Submit::choose_submit(connection_config.clone());
+                        let submit = super::Submit::choose_submit(connection_config.clone())?;
                         submit.process_offline(serialize_to_base64)
                     }
                 }
             }
             Some(network_connection_config) => {
-                let online_signer_access_key_response = self
-                    .rpc_client(network_connection_config.rpc_url().as_str())
+                let online_signer_access_key_response = network_connection_config
+                    .rpc_client()
                     .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
                         block_reference: near_primitives::types::Finality::Final.into(),
                         request: near_primitives::views::QueryRequest::ViewAccessKey {
@@ -225,7 +222,7 @@ impl SignPrivateKey {
                 println!("Your transaction was signed successfully.");
                 match submit {
                     None => {
-                        let submit = super::Submit::choose_submit(connection_config);
+                        let submit = super::Submit::choose_submit(connection_config)?;
                         submit
                             .process_online(
                                 network_connection_config,
@@ -58,11 +58,11 @@ impl AddAccessKeyAction {
     ) -> color_eyre::eyre::Result<Self> {
         let public_key: near_crypto::PublicKey = match item.public_key {
             Some(cli_public_key) => cli_public_key,
-            None => AddAccessKeyAction::input_public_key(),
+            None => AddAccessKeyAction::input_public_key()?,
         };
         let nonce: near_primitives::types::Nonce = match item.nonce {
             Some(cli_nonce) => near_primitives::types::Nonce::from(cli_nonce),
-            None => AddAccessKeyAction::input_access_key_nonce(),
+            None => AddAccessKeyAction::input_access_key_nonce()?,
         };
         let permission: AccessKeyPermission = match item.permission {
             Some(cli_permission) => {
@@ -79,18 +79,20 @@ impl AddAccessKeyAction {
 }
 
 impl AddAccessKeyAction {
-    fn input_access_key_nonce() -> near_primitives::types::Nonce {
-        Input::new()
+    fn input_access_key_nonce() -> color_eyre::eyre::Result<near_primitives::types::Nonce> {
+        crate::common::ensure_interactive("access-key-nonce")?;
+        Ok(Input::new()
             .with_prompt("Enter the nonce for this access key")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
-    fn input_public_key() -> near_crypto::PublicKey {
-        Input::new()
+    fn input_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        crate::common::ensure_interactive("public-key")?;
+        Ok(Input::new()
             .with_prompt("Enter a public key for this access key")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     #[async_recursion(?Send)]
@@ -210,6 +212,7 @@ impl AccessKeyPermission {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("permission")?;
         let select_permission = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Select a permission that you want to add to the access key:")
             .items(&permissions)
@@ -217,16 +220,16 @@ impl AccessKeyPermission {
             .interact()
             .unwrap();
         match variants[select_permission] {
-            AccessKeyPermissionDiscriminants::GrantFunctionCallAccess => Ok(Self::from(
+            AccessKeyPermissionDiscriminants::GrantFunctionCallAccess => Self::from(
                 CliAccessKeyPermission::GrantFunctionCallAccess(Default::default()),
                 connection_config,
                 sender_account_id,
-            )?),
-            AccessKeyPermissionDiscriminants::GrantFullAccess => Ok(Self::from(
+            ),
+            AccessKeyPermissionDiscriminants::GrantFullAccess => Self::from(
                 CliAccessKeyPermission::GrantFullAccess(Default::default()),
                 connection_config,
                 sender_account_id,
-            )?),
+            ),
         }
     }
 }
@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 /// Generate a key pair of private and public keys (use it anywhere you need
-/// Ed25519 keys)
+/// Ed25519 or secp256k1 keys)
 #[derive(Debug, Default, Clone, clap::Clap)]
 #[clap(
     setting(clap::AppSettings::ColoredHelp),
@@ -9,22 +9,36 @@ use std::str::FromStr;
     setting(clap::AppSettings::VersionlessSubcommands)
 )]
 pub struct CliGenerateKeypair {
+    /// ed25519 or secp256k1
+    #[clap(long)]
+    pub key_type: Option<near_crypto::KeyType>,
+    #[clap(flatten)]
+    pub seed_phrase_passphrase: crate::common::CliSeedPhrasePassphrase,
     #[clap(subcommand)]
     permission: Option<super::add_access_key::CliAccessKeyPermission>,
 }
 
 #[derive(Debug, Clone)]
 pub struct GenerateKeypair {
+    pub key_type: near_crypto::KeyType,
+    pub seed_phrase_passphrase: crate::common::CliSeedPhrasePassphrase,
     pub permission: super::add_access_key::AccessKeyPermission,
 }
 
 impl CliGenerateKeypair {
     pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
-        let args = self
+        let mut args = self
             .permission
             .as_ref()
             .map(|subcommand| subcommand.to_cli_args())
             .unwrap_or_default();
+        for arg in self.seed_phrase_passphrase.to_cli_args().into_iter().rev() {
+            args.push_front(arg);
+        }
+        if let Some(key_type) = &self.key_type {
+            args.push_front(key_type.to_string());
+            args.push_front("--key-type".to_owned());
+        }
         args
     }
 }
@@ -32,6 +46,8 @@ impl CliGenerateKeypair {
 impl From<GenerateKeypair> for CliGenerateKeypair {
     fn from(generate_keypair: GenerateKeypair) -> Self {
         Self {
+            key_type: Some(generate_keypair.key_type),
+            seed_phrase_passphrase: generate_keypair.seed_phrase_passphrase,
             permission: Some(generate_keypair.permission.into()),
         }
     }
@@ -43,6 +59,10 @@ impl GenerateKeypair {
         connection_config: Option<crate::common::ConnectionConfig>,
         sender_account_id: near_primitives::types::AccountId,
     ) -> color_eyre::eyre::Result<Self> {
+        let key_type = match item.key_type {
+            Some(key_type) => key_type,
+            None => crate::common::input_key_type()?,
+        };
         let permission: super::add_access_key::AccessKeyPermission = match item.permission {
             Some(cli_permission) => super::add_access_key::AccessKeyPermission::from(
                 cli_permission,
@@ -54,7 +74,11 @@ impl GenerateKeypair {
                 sender_account_id,
             )?,
         };
-        Ok(Self { permission })
+        Ok(Self {
+            key_type,
+            seed_phrase_passphrase: item.seed_phrase_passphrase,
+            permission,
+        })
     }
 }
 
@@ -65,7 +89,11 @@ impl GenerateKeypair {
         network_connection_config: Option<crate::common::ConnectionConfig>,
     ) -> crate::CliResult {
         let key_pair_properties: crate::common::KeyPairProperties =
-            crate::common::generate_keypair().await?;
+            crate::common::generate_keypair(
+                self.key_type,
+                &self.seed_phrase_passphrase.passphrase(true)?,
+            )
+            .await?;
         crate::common::save_access_key_to_keychain(
             network_connection_config.clone(),
             key_pair_properties.clone(),
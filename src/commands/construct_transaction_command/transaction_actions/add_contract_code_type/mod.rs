@@ -53,7 +53,7 @@ impl ContractFile {
     ) -> color_eyre::eyre::Result<Self> {
         let file_path = match item.file_path {
             Some(cli_file_path) => cli_file_path,
-            None => ContractFile::input_file_path(),
+            None => ContractFile::input_file_path()?,
         };
         let skip_next_action: super::NextAction = match item.next_action {
             Some(cli_skip_action) => super::NextAction::from_cli_skip_next_action(
@@ -71,13 +71,14 @@ impl ContractFile {
 }
 
 impl ContractFile {
-    fn input_file_path() -> std::path::PathBuf {
+    fn input_file_path() -> color_eyre::eyre::Result<std::path::PathBuf> {
         println!();
+        crate::common::ensure_interactive("file-path")?;
         let input_file_path: String = Input::new()
             .with_prompt("What is a file location of the contract?")
             .interact_text()
             .unwrap();
-        input_file_path.into()
+        Ok(input_file_path.into())
     }
 
     #[async_recursion(?Send)]
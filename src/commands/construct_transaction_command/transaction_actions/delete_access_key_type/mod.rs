@@ -53,7 +53,7 @@ impl DeleteAccessKeyAction {
     ) -> color_eyre::eyre::Result<Self> {
         let public_key: near_crypto::PublicKey = match item.public_key {
             Some(cli_public_key) => cli_public_key,
-            None => DeleteAccessKeyAction::input_public_key(),
+            None => DeleteAccessKeyAction::input_public_key()?,
         };
         let skip_next_action: super::NextAction = match item.next_action {
             Some(cli_skip_action) => super::NextAction::from_cli_skip_next_action(
@@ -71,11 +71,12 @@ impl DeleteAccessKeyAction {
 }
 
 impl DeleteAccessKeyAction {
-    pub fn input_public_key() -> near_crypto::PublicKey {
-        Input::new()
+    pub fn input_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        crate::common::ensure_interactive("public-key")?;
+        Ok(Input::new()
             .with_prompt("Enter the access key to remove it")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     #[async_recursion(?Send)]
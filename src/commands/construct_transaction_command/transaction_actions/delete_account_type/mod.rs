@@ -55,7 +55,7 @@ impl DeleteAccountAction {
     ) -> color_eyre::eyre::Result<Self> {
         let beneficiary_id: near_primitives::types::AccountId = match item.beneficiary_id {
             Some(cli_account_id) => cli_account_id,
-            None => DeleteAccountAction::input_beneficiary_id(),
+            None => DeleteAccountAction::input_beneficiary_id()?,
         };
         let skip_next_action: super::NextAction = match item.next_action {
             Some(cli_skip_action) => super::NextAction::from_cli_skip_next_action(
@@ -73,12 +73,13 @@ impl DeleteAccountAction {
 }
 
 impl DeleteAccountAction {
-    pub fn input_beneficiary_id() -> near_primitives::types::AccountId {
+    pub fn input_beneficiary_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
         println!();
-        Input::new()
+        crate::common::ensure_interactive("beneficiary-id")?;
+        Ok(Input::new()
             .with_prompt("Enter the beneficiary ID to delete this account ID")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     #[async_recursion(?Send)]
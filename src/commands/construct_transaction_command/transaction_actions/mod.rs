@@ -140,6 +140,7 @@ impl NextAction {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("next-action")?;
         let select_next_action = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Select an action that you want to add to the action:")
             .items(&next_action)
@@ -222,8 +223,8 @@ impl SelectAction {
                 cli_transaction_subcommand,
                 connection_config,
                 sender_account_id,
-            ),
-            None => ActionSubcommand::choose_action_command(connection_config, sender_account_id),
+            )?,
+            None => ActionSubcommand::choose_action_command(connection_config, sender_account_id)?,
         };
         Ok(Self {
             transaction_subcommand,
@@ -367,74 +368,66 @@ impl ActionSubcommand {
         item: CliActionSubcommand,
         connection_config: Option<crate::common::ConnectionConfig>,
         sender_account_id: near_primitives::types::AccountId,
-    ) -> Self {
+    ) -> color_eyre::eyre::Result<Self> {
         match item {
             CliActionSubcommand::TransferNEARTokens(cli_transfer_near_token) => {
-                Self::TransferNEARTokens(
+                Ok(Self::TransferNEARTokens(
                     self::transfer_near_tokens_type::TransferNEARTokensAction::from(
                         cli_transfer_near_token,
                         connection_config,
                         sender_account_id,
-                    )
-                    .unwrap(),
-                )
+                    )?,
+                ))
             }
-            CliActionSubcommand::CreateAccount(cli_create_account) => Self::CreateAccount(
+            CliActionSubcommand::CreateAccount(cli_create_account) => Ok(Self::CreateAccount(
                 self::create_account_type::CreateAccountAction::from(
                     cli_create_account,
                     connection_config,
                     sender_account_id,
-                )
-                .unwrap(),
-            ),
-            CliActionSubcommand::DeleteAccount(cli_delete_account) => Self::DeleteAccount(
+                )?,
+            )),
+            CliActionSubcommand::DeleteAccount(cli_delete_account) => Ok(Self::DeleteAccount(
                 self::delete_account_type::DeleteAccountAction::from(
                     cli_delete_account,
                     connection_config,
                     sender_account_id,
-                )
-                .unwrap(),
-            ),
-            CliActionSubcommand::AddAccessKey(cli_add_access_key) => Self::AddAccessKey(
+                )?,
+            )),
+            CliActionSubcommand::AddAccessKey(cli_add_access_key) => Ok(Self::AddAccessKey(
                 self::add_access_key_mode::AddAccessKeyMode::from(
                     cli_add_access_key,
                     connection_config,
                     sender_account_id,
-                )
-                .unwrap(),
-            ),
-            CliActionSubcommand::DeleteAccessKey(cli_delete_access_key) => Self::DeleteAccessKey(
-                self::delete_access_key_type::DeleteAccessKeyAction::from(
+                )?,
+            )),
+            CliActionSubcommand::DeleteAccessKey(cli_delete_access_key) => Ok(
+                Self::DeleteAccessKey(self::delete_access_key_type::DeleteAccessKeyAction::from(
                     cli_delete_access_key,
                     connection_config,
                     sender_account_id,
-                )
-                .unwrap(),
+                )?),
             ),
-            CliActionSubcommand::StakeNEARTokens(cli_stake_near_token) => Self::StakeNEARTokens(
-                self::stake_near_tokens_type::StakeNEARTokensAction::from(
+            CliActionSubcommand::StakeNEARTokens(cli_stake_near_token) => Ok(
+                Self::StakeNEARTokens(self::stake_near_tokens_type::StakeNEARTokensAction::from(
                     cli_stake_near_token,
                     connection_config,
                     sender_account_id,
-                )
-                .unwrap(),
+                )?),
             ),
-            CliActionSubcommand::CallFunction(cli_call_function) => Self::CallFunction(
+            CliActionSubcommand::CallFunction(cli_call_function) => Ok(Self::CallFunction(
                 self::call_function_type::CallFunctionAction::from(
                     cli_call_function,
                     connection_config,
                     sender_account_id,
-                )
-                .unwrap(),
-            ),
-            CliActionSubcommand::AddContractCode(cli_contract_file) => Self::AddContractCode(
+                )?,
+            )),
+            CliActionSubcommand::AddContractCode(cli_contract_file) => Ok(Self::AddContractCode(
                 self::add_contract_code_type::ContractFile::from(
                     cli_contract_file,
                     connection_config,
                     sender_account_id,
-                )
-                .unwrap(),
-            ),
+                )?,
+            )),
         }
     }
 }
@@ -443,13 +436,14 @@ impl ActionSubcommand {
     pub fn choose_action_command(
         connection_config: Option<crate::common::ConnectionConfig>,
         sender_account_id: near_primitives::types::AccountId,
-    ) -> ActionSubcommand {
+    ) -> color_eyre::eyre::Result<ActionSubcommand> {
         println!();
         let variants = ActionSubcommandDiscriminants::iter().collect::<Vec<_>>();
         let action_subcommands = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("action-command")?;
         let select_action_subcommand = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Select an action that you want to add to the action:")
             .items(&action_subcommands)
@@ -78,20 +78,20 @@ impl StakeNEARTokensAction {
                                 "You need to enter a value of no more than {}",
                                 account_balance
                             );
-                            StakeNEARTokensAction::input_stake(Some(account_balance))
+                            StakeNEARTokensAction::input_stake(Some(account_balance))?
                         }
                     }
-                    None => StakeNEARTokensAction::input_stake(Some(account_balance)),
+                    None => StakeNEARTokensAction::input_stake(Some(account_balance))?,
                 }
             }
             None => match item.stake {
                 Some(cli_amount) => cli_amount,
-                None => StakeNEARTokensAction::input_stake(None),
+                None => StakeNEARTokensAction::input_stake(None)?,
             },
         };
         let public_key: near_crypto::PublicKey = match item.public_key {
             Some(cli_public_key) => cli_public_key,
-            None => StakeNEARTokensAction::input_public_key(),
+            None => StakeNEARTokensAction::input_public_key()?,
         };
         let skip_next_action: super::NextAction = match item.next_action {
             Some(cli_skip_action) => super::NextAction::from_cli_skip_next_action(
@@ -110,17 +110,19 @@ impl StakeNEARTokensAction {
 }
 
 impl StakeNEARTokensAction {
-    fn input_public_key() -> near_crypto::PublicKey {
-        Input::new()
+    fn input_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        crate::common::ensure_interactive("public-key")?;
+        Ok(Input::new()
             .with_prompt("Enter a public key for this stake")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     fn input_stake(
         account_balance: Option<crate::common::NearBalance>,
-    ) -> crate::common::NearBalance {
-        match account_balance {
+    ) -> color_eyre::eyre::Result<crate::common::NearBalance> {
+        crate::common::ensure_interactive("stake")?;
+        Ok(match account_balance {
             Some(account_balance) => loop {
                 let input_stake: crate::common::NearBalance = Input::new()
                             .with_prompt("How many NEAR Tokens do you want to stake? (example: 10NEAR or 0.5near or 10000yoctonear)")
@@ -140,7 +142,7 @@ impl StakeNEARTokensAction {
                         .with_prompt("How many NEAR Tokens do you want to stake? (example: 10NEAR or 0.5near or 10000yoctonear)")
                         .interact_text()
                         .unwrap()
-        }
+        })
     }
 
     #[async_recursion(?Send)]
@@ -59,11 +59,11 @@ impl DeleteAccessKeyAction {
         connection_config: Option<crate::common::ConnectionConfig>,
         sender_account_id: near_primitives::types::AccountId,
     ) -> color_eyre::eyre::Result<Self> {
-        Ok(Self::from(
+        Self::from(
             CliDeleteAccessKeyAction::PublicKey(Default::default()),
             connection_config,
             sender_account_id,
-        )?)
+        )
     }
 
     pub async fn process(
@@ -134,7 +134,7 @@ impl DeleteAccessKeyType {
     ) -> color_eyre::eyre::Result<Self> {
         let public_key: near_crypto::PublicKey = match item.public_key {
             Some(cli_public_key) => cli_public_key,
-            None => DeleteAccessKeyType::input_public_key(),
+            None => DeleteAccessKeyType::input_public_key()?,
         };
         let sign_option = match item.sign_option {
             Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(cli_sign_transaction, connection_config, sender_account_id)?,
@@ -148,11 +148,12 @@ impl DeleteAccessKeyType {
 }
 
 impl DeleteAccessKeyType {
-    fn input_public_key() -> near_crypto::PublicKey {
-        Input::new()
+    fn input_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        crate::common::ensure_interactive("public-key")?;
+        Ok(Input::new()
             .with_prompt("Enter a public key for this access key")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
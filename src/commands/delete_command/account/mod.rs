@@ -86,6 +86,7 @@ impl DeleteAccountAction {
         connection_config: Option<crate::common::ConnectionConfig>,
     ) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
         loop {
+            crate::common::ensure_interactive("beneficiary-id")?;
             let account_id: near_primitives::types::AccountId = Input::new()
                 .with_prompt("Enter the beneficiary ID to delete this account ID")
                 .interact_text()
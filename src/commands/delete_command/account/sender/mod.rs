@@ -80,6 +80,7 @@ impl Sender {
         connection_config: Option<crate::common::ConnectionConfig>,
     ) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
         loop {
+            crate::common::ensure_interactive("sender-account-id")?;
             let account_id: near_primitives::types::AccountId = Input::new()
                 .with_prompt("Which account ID do you need to remove?")
                 .interact_text()
@@ -171,11 +172,11 @@ impl SendTo {
         connection_config: Option<crate::common::ConnectionConfig>,
         sender_account_id: near_primitives::types::AccountId,
     ) -> color_eyre::eyre::Result<Self> {
-        Ok(Self::from(
+        Self::from(
             CliSendTo::Beneficiary(Default::default()),
             connection_config,
             sender_account_id,
-        )?)
+        )
     }
 
     pub async fn process(
@@ -1,7 +1,7 @@
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
-mod access_key;
+pub(crate) mod access_key;
 mod account;
 
 /// инструмент выбора to delete action
@@ -104,10 +104,10 @@ impl Action {
     fn from(item: CliAction) -> color_eyre::eyre::Result<Self> {
         match item {
             CliAction::AccessKey(cli_operation_mode) => Ok(Action::AccessKey(
-                self::access_key::operation_mode::OperationMode::from(cli_operation_mode).unwrap(),
+                self::access_key::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
             CliAction::Account(cli_operation_mode) => Ok(Action::Account(
-                self::account::operation_mode::OperationMode::from(cli_operation_mode).unwrap(),
+                self::account::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
         }
     }
@@ -121,6 +121,7 @@ impl Action {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("action")?;
         let selected_action = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Сhoose what you want to delete")
             .items(&actions)
@@ -131,7 +132,7 @@ impl Action {
             ActionDiscriminants::AccessKey => CliAction::AccessKey(Default::default()),
             ActionDiscriminants::Account => CliAction::Account(Default::default()),
         };
-        Ok(Self::from(cli_action)?)
+        Self::from(cli_action)
     }
 
     pub async fn process(
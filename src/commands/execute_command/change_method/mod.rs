@@ -65,6 +65,7 @@ impl CallFunction {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("call-function")?;
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Call your function")
             .items(&commands)
@@ -74,7 +75,7 @@ impl CallFunction {
         let cli_call = match variants[selection] {
             CallFunctionDiscriminants::Call => CliCallFunction::Call(Default::default()),
         };
-        Ok(Self::from(cli_call, connection_config)?)
+        Self::from(cli_call, connection_config)
     }
 
     pub async fn process(
@@ -121,6 +121,7 @@ impl Method {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("method")?;
         let selected_method = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Choose your method")
             .items(&methods)
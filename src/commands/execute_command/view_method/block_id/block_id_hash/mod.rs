@@ -29,26 +29,23 @@ impl From<BlockIdHash> for CliBlockIdHash {
     }
 }
 
-impl From<CliBlockIdHash> for BlockIdHash {
-    fn from(item: CliBlockIdHash) -> Self {
+impl BlockIdHash {
+    pub fn from(item: CliBlockIdHash) -> color_eyre::eyre::Result<Self> {
         let block_id_hash: near_primitives::hash::CryptoHash = match item.block_id_hash {
             Some(cli_block_id_hash) => cli_block_id_hash,
-            None => BlockIdHash::input_block_id_hash(),
+            None => BlockIdHash::input_block_id_hash()?,
         };
-        Self { block_id_hash }
+        Ok(Self { block_id_hash })
     }
 }
 
 impl BlockIdHash {
-    pub fn input_block_id_hash() -> near_primitives::hash::CryptoHash {
-        Input::new()
+    pub fn input_block_id_hash() -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+        crate::common::ensure_interactive("block-id-hash")?;
+        Ok(Input::new()
             .with_prompt("Type the block ID hash for this contract")
             .interact_text()
-            .unwrap()
-    }
-
-    fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
+            .unwrap())
     }
 
     pub async fn process(
@@ -60,8 +57,8 @@ impl BlockIdHash {
     ) -> crate::CliResult {
         let args: near_primitives::types::FunctionArgs =
             near_primitives::types::FunctionArgs::from(args);
-        let query_view_method_response = self
-            .rpc_client(network_connection_config.archival_rpc_url().as_str())
+        let query_view_method_response = network_connection_config
+            .archival_rpc_client()
             .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
                 block_reference: near_primitives::types::BlockReference::BlockId(
                     near_primitives::types::BlockId::Hash(self.block_id_hash.clone()),
@@ -87,6 +84,7 @@ impl BlockIdHash {
         let call_result_str = String::from_utf8(call_result).unwrap();
         let serde_call_result: serde_json::Value = serde_json::from_str(&call_result_str)
             .map_err(|err| color_eyre::Report::msg(format!("serde json: {:?}", err)))?;
+        crate::common::add_json_output("result", serde_call_result.clone());
         println!("--------------");
         println!();
         println!(
@@ -29,26 +29,24 @@ impl From<BlockIdHeight> for CliBlockIdHeight {
     }
 }
 
-impl From<CliBlockIdHeight> for BlockIdHeight {
-    fn from(item: CliBlockIdHeight) -> Self {
+impl BlockIdHeight {
+    pub fn from(item: CliBlockIdHeight) -> color_eyre::eyre::Result<Self> {
         let block_id_height: near_primitives::types::BlockHeight = match item.block_id_height {
             Some(cli_block_id_hash) => cli_block_id_hash,
-            None => BlockIdHeight::input_block_id_height(),
+            None => BlockIdHeight::input_block_id_height()?,
         };
-        Self { block_id_height }
+        Ok(Self { block_id_height })
     }
 }
 
 impl BlockIdHeight {
-    pub fn input_block_id_height() -> near_primitives::types::BlockHeight {
-        Input::new()
+    pub fn input_block_id_height() -> color_eyre::eyre::Result<near_primitives::types::BlockHeight>
+    {
+        crate::common::ensure_interactive("block-id-height")?;
+        Ok(Input::new()
             .with_prompt("Type the block ID height for this contract")
             .interact_text()
-            .unwrap()
-    }
-
-    fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
+            .unwrap())
     }
 
     pub async fn process(
@@ -60,8 +58,8 @@ impl BlockIdHeight {
     ) -> crate::CliResult {
         let args: near_primitives::types::FunctionArgs =
             near_primitives::types::FunctionArgs::from(args);
-        let query_view_method_response = self
-            .rpc_client(network_connection_config.archival_rpc_url().as_str())
+        let query_view_method_response = network_connection_config
+            .archival_rpc_client()
             .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
                 block_reference: near_primitives::types::BlockReference::BlockId(
                     near_primitives::types::BlockId::Height(self.block_id_height.clone()),
@@ -87,6 +85,7 @@ impl BlockIdHeight {
         let call_result_str = String::from_utf8(call_result).unwrap();
         let serde_call_result: serde_json::Value = serde_json::from_str(&call_result_str)
             .map_err(|err| color_eyre::Report::msg(format!("serde json: {:?}", err)))?;
+        crate::common::add_json_output("result", serde_call_result.clone());
         println!("--------------");
         println!();
         println!(
@@ -50,44 +50,46 @@ impl From<CallFunctionView> for CliCallFunctionView {
     }
 }
 
-impl From<CliCallFunctionView> for CallFunctionView {
-    fn from(item: CliCallFunctionView) -> Self {
+impl CallFunctionView {
+    pub fn from(item: CliCallFunctionView) -> color_eyre::eyre::Result<Self> {
         let method_name: String = match item.method_name {
             Some(cli_method_name) => cli_method_name,
-            None => CallFunctionView::input_method_name(),
+            None => CallFunctionView::input_method_name()?,
         };
         let function_args: Vec<u8> = match item.function_args {
             Some(cli_args) => cli_args.into_bytes(),
-            None => CallFunctionView::input_function_args(),
+            None => CallFunctionView::input_function_args()?,
         };
         let selected_block_id: super::block_id::BlockId = match item.selected_block_id {
-            Some(cli_block_id) => cli_block_id.into(),
-            None => super::block_id::BlockId::choose_block_id(),
+            Some(cli_block_id) => super::block_id::BlockId::from(cli_block_id)?,
+            None => super::block_id::BlockId::choose_block_id()?,
         };
-        Self {
+        Ok(Self {
             method_name,
             function_args,
             selected_block_id,
-        }
+        })
     }
 }
 
 impl CallFunctionView {
-    fn input_method_name() -> String {
+    fn input_method_name() -> color_eyre::eyre::Result<String> {
         println!();
-        Input::new()
+        crate::common::ensure_interactive("method-name")?;
+        Ok(Input::new()
             .with_prompt("Enter a method name")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
-    fn input_function_args() -> Vec<u8> {
+    fn input_function_args() -> color_eyre::eyre::Result<Vec<u8>> {
         println!();
+        crate::common::ensure_interactive("function-args")?;
         let input: String = Input::new()
             .with_prompt("Enter args for function")
             .interact_text()
             .unwrap();
-        input.into_bytes()
+        Ok(input.into_bytes())
     }
 
     pub async fn process(
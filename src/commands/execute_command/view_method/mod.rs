@@ -39,24 +39,25 @@ impl From<CallFunction> for CliCallFunction {
     }
 }
 
-impl From<CliCallFunction> for CallFunction {
-    fn from(item: CliCallFunction) -> Self {
+impl CallFunction {
+    pub fn from(item: CliCallFunction) -> color_eyre::eyre::Result<Self> {
         match item {
-            CliCallFunction::Call(cli_call_function_view) => {
-                CallFunction::Call(cli_call_function_view.into())
-            }
+            CliCallFunction::Call(cli_call_function_view) => Ok(CallFunction::Call(
+                self::call_function_type::CallFunctionView::from(cli_call_function_view)?,
+            )),
         }
     }
 }
 
 impl CallFunction {
-    pub fn choose_call_function() -> Self {
+    pub fn choose_call_function() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = CallFunctionDiscriminants::iter().collect::<Vec<_>>();
         let commands = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("call-function")?;
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Call your function")
             .items(&commands)
@@ -133,8 +133,8 @@ impl Receiver {
             None => Receiver::input_contract_account_id(connection_config)?,
         };
         let call = match item.call {
-            Some(cli_call) => cli_call.into(),
-            None => super::CallFunction::choose_call_function(),
+            Some(cli_call) => super::CallFunction::from(cli_call)?,
+            None => super::CallFunction::choose_call_function()?,
         };
         Ok(Self {
             contract_account_id,
@@ -148,6 +148,7 @@ impl Receiver {
         connection_config: crate::common::ConnectionConfig,
     ) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
         loop {
+            crate::common::ensure_interactive("contract-account-id")?;
             let contract_account_id: near_primitives::types::AccountId = Input::new()
                 .with_prompt("What is the account ID of the contract?")
                 .interact_text()
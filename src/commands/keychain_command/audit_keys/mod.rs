@@ -0,0 +1,320 @@
+/// проверка хранилища: права доступа к файлам, открытые seed-фразы, ключи, удалённые из аккаунтов
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliAuditKeys {
+    /// имя сети из конфигурационного файла; без него проверяются ключи всех сетей
+    network: Option<crate::common::ConnectionConfig>,
+}
+
+#[derive(Debug)]
+struct Finding {
+    /// `None` for the legacy directory, which is in the search path of every network
+    network: Option<String>,
+    path: std::path::PathBuf,
+    issue: &'static str,
+    message: String,
+}
+
+/// A key of the legacy directory, checked on every audited network and reported once
+#[derive(Debug)]
+struct LegacyKey {
+    key: crate::keychain::KeychainKey,
+    account_exists: bool,
+    key_exists: bool,
+}
+
+impl CliAuditKeys {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(network) = &self.network {
+            args.push_front(network.to_string());
+        }
+        args
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let connection_configs = match self.network {
+            Some(connection_config) => vec![connection_config],
+            None => crate::common::ConnectionConfig::preselected_or_all_networks()?,
+        };
+        let legacy_dir = dirs::home_dir()
+            .expect("Impossible to get your home dir!")
+            .join(crate::consts::DIR_NAME_KEY_CHAIN);
+        let mut findings = vec![];
+        // The files and directories shared by the networks are checked once
+        let mut audited_paths: Vec<std::path::PathBuf> = vec![];
+        let mut legacy_keys: Vec<LegacyKey> = vec![];
+        for connection_config in connection_configs {
+            let mut key_files = vec![];
+            let mut dirs = vec![];
+            for credentials_dir in
+                crate::keychain::credentials_search_path(Some(&connection_config))?
+            {
+                if !credentials_dir.exists() {
+                    continue;
+                }
+                println!(
+                    "\nChecking the keys of network <{}> in {}",
+                    connection_config.network_name(),
+                    credentials_dir.display()
+                );
+                key_files.extend(crate::keychain::scan_key_files(&credentials_dir)?);
+                dirs.push(credentials_dir);
+            }
+            let finding = |path: &std::path::Path, issue, message| Finding {
+                network: if path.starts_with(&legacy_dir) {
+                    None
+                } else {
+                    Some(connection_config.network_name().to_owned())
+                },
+                path: path.to_owned(),
+                issue,
+                message,
+            };
+            for key in key_files.iter() {
+                if key.path.starts_with(&legacy_dir)
+                    && !legacy_keys
+                        .iter()
+                        .any(|legacy_key| legacy_key.key.path == key.path)
+                {
+                    legacy_keys.push(LegacyKey {
+                        key: key.clone(),
+                        account_exists: false,
+                        key_exists: false,
+                    });
+                }
+            }
+
+            for key in key_files.iter() {
+                if let Some(dir) = key.path.parent() {
+                    if !dirs.iter().any(|listed_dir| listed_dir == dir) {
+                        dirs.push(dir.to_owned());
+                    }
+                }
+            }
+            for dir in dirs.iter() {
+                if audited_paths.contains(dir) {
+                    continue;
+                }
+                audited_paths.push(dir.clone());
+                if let Some(mode) = crate::keychain::permissive_mode(dir) {
+                    findings.push(finding(
+                        dir,
+                        "permissions",
+                        format!(
+                            "the directory is accessible by other users (mode {:o}, expected 700)",
+                            mode
+                        ),
+                    ));
+                }
+            }
+            for key in key_files.iter() {
+                if audited_paths.contains(&key.path) {
+                    continue;
+                }
+                audited_paths.push(key.path.clone());
+                if let Some(mode) = crate::keychain::permissive_mode(&key.path) {
+                    findings.push(finding(
+                        &key.path,
+                        "permissions",
+                        format!(
+                            "the key file is readable by other users (mode {:o}, expected 600)",
+                            mode
+                        ),
+                    ));
+                }
+                if !key.encrypted {
+                    let has_seed_phrase = crate::keychain::read_key_file(&key.path)
+                        .map(|key_file| {
+                            key_file["master_seed_phrase"]
+                                .as_str()
+                                .map_or(false, |seed_phrase| !seed_phrase.is_empty())
+                        })
+                        .unwrap_or(false);
+                    if has_seed_phrase {
+                        findings.push(finding(
+                            &key.path,
+                            "plaintext_seed_phrase",
+                            "the seed phrase is stored unencrypted (see `near-cli utils encrypt-keychain`)"
+                                .to_owned(),
+                        ));
+                    }
+                }
+            }
+
+            let mut account_ids = key_files
+                .iter()
+                .map(|key| key.account_id.clone())
+                .collect::<Vec<_>>();
+            account_ids.sort();
+            account_ids.dedup();
+            for account_id in account_ids {
+                let account_keys = key_files
+                    .iter()
+                    .filter(|key| key.account_id == account_id)
+                    .collect::<Vec<_>>();
+                let parsed_account_id: near_primitives::types::AccountId = match account_id.parse()
+                {
+                    Ok(parsed_account_id) => parsed_account_id,
+                    Err(_) => {
+                        for key in account_keys
+                            .into_iter()
+                            .filter(|key| !key.path.starts_with(&legacy_dir))
+                        {
+                            findings.push(finding(
+                                &key.path,
+                                "invalid_account_id",
+                                format!("<{}> is not a valid account ID", account_id),
+                            ));
+                        }
+                        continue;
+                    }
+                };
+                let query_view_method_response = connection_config
+                    .rpc_client()
+                    .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                        block_reference: near_primitives::types::Finality::Final.into(),
+                        request: near_primitives::views::QueryRequest::ViewAccessKeyList {
+                            account_id: parsed_account_id,
+                        },
+                    })
+                    .await;
+                match query_view_method_response {
+                    Ok(rpc_query_response) => {
+                        let access_key_list =
+                            if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKeyList(
+                                result,
+                            ) = rpc_query_response.kind
+                            {
+                                result
+                            } else {
+                                return Err(color_eyre::Report::msg("Error call result"));
+                            };
+                        for key in account_keys {
+                            let key_exists = access_key_list.keys.iter().any(|access_key| {
+                                access_key.public_key.to_string() == key.public_key
+                            });
+                            if let Some(legacy_key) = legacy_keys
+                                .iter_mut()
+                                .find(|legacy_key| legacy_key.key.path == key.path)
+                            {
+                                legacy_key.account_exists = true;
+                                legacy_key.key_exists |= key_exists;
+                            } else if !key_exists {
+                                findings.push(finding(
+                                    &key.path,
+                                    "key_not_on_chain",
+                                    format!(
+                                        "<{}> no longer has the access key {}",
+                                        account_id, key.public_key
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+                    Err(err)
+                        if crate::rpc_client::handler_error_cause(&err)
+                            == Some("UNKNOWN_ACCOUNT") =>
+                    {
+                        for key in account_keys
+                            .into_iter()
+                            .filter(|key| !key.path.starts_with(&legacy_dir))
+                        {
+                            findings.push(finding(
+                                &key.path,
+                                "unknown_account",
+                                format!("<{}> does not exist on-chain", account_id),
+                            ));
+                        }
+                    }
+                    Err(err) => {
+                        println!(
+                            "Could not check the access keys of <{}> on-chain: {:?}",
+                            account_id, err
+                        );
+                        // A legacy key that could not be checked on every network is not reported
+                        for legacy_key in legacy_keys
+                            .iter_mut()
+                            .filter(|legacy_key| legacy_key.key.account_id == account_id)
+                        {
+                            legacy_key.key_exists = true;
+                        }
+                    }
+                }
+            }
+        }
+        // A legacy key is only reported when none of the networks has it
+        for legacy_key in legacy_keys {
+            if legacy_key.key_exists {
+                continue;
+            }
+            let (issue, message) = if legacy_key
+                .key
+                .account_id
+                .parse::<near_primitives::types::AccountId>()
+                .is_err()
+            {
+                (
+                    "invalid_account_id",
+                    format!("<{}> is not a valid account ID", legacy_key.key.account_id),
+                )
+            } else if legacy_key.account_exists {
+                (
+                    "key_not_on_chain",
+                    format!(
+                        "<{}> no longer has the access key {} on any audited network",
+                        legacy_key.key.account_id, legacy_key.key.public_key
+                    ),
+                )
+            } else {
+                (
+                    "unknown_account",
+                    format!(
+                        "<{}> does not exist on any audited network",
+                        legacy_key.key.account_id
+                    ),
+                )
+            };
+            findings.push(Finding {
+                network: None,
+                path: legacy_key.key.path,
+                issue,
+                message,
+            });
+        }
+
+        crate::common::add_json_output(
+            "audit",
+            serde_json::json!(findings
+                .iter()
+                .map(|finding| serde_json::json!({
+                    "network": finding.network,
+                    "file": finding.path,
+                    "issue": finding.issue,
+                    "message": finding.message,
+                }))
+                .collect::<Vec<_>>()),
+        );
+        if findings.is_empty() {
+            println!("\nNo issues found");
+            return Ok(());
+        }
+        println!();
+        for finding in findings.iter() {
+            println!(
+                "[{}] {}: {}",
+                finding.network.as_deref().unwrap_or("all networks"),
+                finding.path.display(),
+                finding.message
+            );
+        }
+        Err(color_eyre::Report::msg(format!(
+            "The keychain audit found {} issue(s)",
+            findings.len()
+        )))
+    }
+}
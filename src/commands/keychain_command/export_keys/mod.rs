@@ -0,0 +1,143 @@
+/// сохранение ключей хранилища в файл резервной копии
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliExportKeys {
+    /// имя сети из конфигурационного файла; без него сохраняются ключи всех сетей
+    network: Option<crate::common::ConnectionConfig>,
+    /// файл резервной копии
+    #[clap(long)]
+    file: Option<std::path::PathBuf>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportKeys {
+    network: Option<crate::common::ConnectionConfig>,
+    file: std::path::PathBuf,
+}
+
+/// The backup bundle: the key files as they are saved, encrypted ones stay encrypted
+#[derive(Debug, serde::Serialize)]
+struct KeychainBundle {
+    version: u32,
+    keys: Vec<BundledKey>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct BundledKey {
+    network: String,
+    credentials_dir: std::path::PathBuf,
+    account_id: String,
+    public_key: String,
+    key_file: serde_json::Value,
+}
+
+impl CliExportKeys {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(file) = &self.file {
+            args.push_front(file.display().to_string());
+            args.push_front("--file".to_owned());
+        }
+        if let Some(network) = &self.network {
+            args.push_front(network.to_string());
+        }
+        args
+    }
+}
+
+impl From<ExportKeys> for CliExportKeys {
+    fn from(export_keys: ExportKeys) -> Self {
+        Self {
+            network: export_keys.network,
+            file: Some(export_keys.file),
+        }
+    }
+}
+
+impl ExportKeys {
+    pub fn from(item: CliExportKeys) -> color_eyre::eyre::Result<Self> {
+        let file = match item.file {
+            Some(file) => file,
+            None => ExportKeys::input_file()?,
+        };
+        Ok(Self {
+            network: item.network,
+            file,
+        })
+    }
+}
+
+impl ExportKeys {
+    fn input_file() -> color_eyre::eyre::Result<std::path::PathBuf> {
+        crate::common::ensure_interactive("file")?;
+        Ok(dialoguer::Input::<String>::new()
+            .with_prompt("Where to save the backup bundle?")
+            .with_initial_text("near-credentials-backup.json")
+            .interact_text()
+            .unwrap()
+            .into())
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let connection_configs = match self.network {
+            Some(connection_config) => vec![connection_config],
+            None => crate::common::ConnectionConfig::preselected_or_all_networks()?,
+        };
+        let mut keys = vec![];
+        // The legacy directory is in the search path of every network, it is saved once
+        let mut exported_dirs = vec![];
+        for connection_config in connection_configs {
+            for credentials_dir in
+                crate::keychain::credentials_search_path(Some(&connection_config))?
+            {
+                if exported_dirs.contains(&credentials_dir) {
+                    continue;
+                }
+                for key in crate::keychain::list_keys(&credentials_dir)? {
+                    let key_file: serde_json::Value =
+                        serde_json::from_str(&std::fs::read_to_string(&key.path)?)?;
+                    keys.push(BundledKey {
+                        network: connection_config.network_name().to_owned(),
+                        credentials_dir: credentials_dir.clone(),
+                        account_id: key.account_id,
+                        public_key: key.public_key,
+                        key_file,
+                    });
+                }
+                exported_dirs.push(credentials_dir);
+            }
+        }
+        let plaintext_keys_count = keys
+            .iter()
+            .filter(|key| !crate::keychain::is_encrypted(&key.key_file))
+            .count();
+        let bundle = KeychainBundle { version: 1, keys };
+        crate::keychain::write_private_file(
+            &self.file,
+            serde_json::to_string_pretty(&bundle)?.as_bytes(),
+        )?;
+        println!(
+            "\n{} keys have been saved to {}",
+            bundle.keys.len(),
+            self.file.display()
+        );
+        if plaintext_keys_count > 0 {
+            println!(
+                "{} of them are not encrypted, so keep the file as safe as the keychain itself",
+                plaintext_keys_count
+            );
+        }
+        crate::common::add_json_output(
+            "bundle",
+            serde_json::json!({
+                "file": self.file,
+                "keys_count": bundle.keys.len(),
+            }),
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,302 @@
+use dialoguer::{theme::ColorfulTheme, Select};
+use std::str::FromStr;
+
+/// сохранение в хранилище ключа, заданного приватным ключом или seed-фразой
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliImportKey {
+    /// имя сети из конфигурационного файла (например, testnet или mainnet)
+    network: Option<crate::common::ConnectionConfig>,
+    #[clap(long)]
+    account_id: Option<near_primitives::types::AccountId>,
+    #[clap(flatten)]
+    key: CliImportedKey,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportKey {
+    connection_config: crate::common::ConnectionConfig,
+    account_id: near_primitives::types::AccountId,
+    key: ImportedKey,
+}
+
+/// приватный ключ или seed-фраза
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliImportedKey {
+    /// the private key itself, @path/to/file, - (stdin) or env:VAR_NAME
+    #[clap(long, conflicts_with = "seed-phrase")]
+    private_key: Option<crate::common::SecretArg<near_crypto::SecretKey>>,
+    /// the seed phrase itself, @path/to/file, - (stdin) or env:VAR_NAME
+    #[clap(long)]
+    seed_phrase: Option<crate::common::SecretArg<String>>,
+    /// HD path of the key derived from the seed phrase (m/44'/397'/0' by default)
+    #[clap(long, requires = "seed-phrase")]
+    seed_phrase_hd_path: Option<slip10::BIP32Path>,
+    /// type of the key derived from the seed phrase: ed25519 (by default) or secp256k1
+    #[clap(long, requires = "seed-phrase")]
+    key_type: Option<near_crypto::KeyType>,
+    #[clap(flatten)]
+    seed_phrase_passphrase: crate::common::CliSeedPhrasePassphrase,
+}
+
+#[derive(Debug, Clone)]
+pub enum ImportedKey {
+    PrivateKey(crate::common::SecretArg<near_crypto::SecretKey>),
+    SeedPhrase {
+        seed_phrase: crate::common::SecretArg<String>,
+        seed_phrase_hd_path: slip10::BIP32Path,
+        key_type: near_crypto::KeyType,
+        seed_phrase_passphrase: crate::common::CliSeedPhrasePassphrase,
+    },
+}
+
+/// The imported key with its public key known, ready to be saved for an account
+#[derive(Debug, Clone)]
+pub enum ImportedKeyPair {
+    PrivateKey(near_crypto::SecretKey),
+    SeedPhrase(crate::common::KeyPairProperties),
+}
+
+impl CliImportKey {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.key.to_cli_args();
+        if let Some(account_id) = &self.account_id {
+            args.push_front(account_id.to_string());
+            args.push_front("--account-id".to_owned());
+        }
+        if let Some(network) = &self.network {
+            args.push_front(network.to_string());
+        }
+        args
+    }
+}
+
+impl From<ImportKey> for CliImportKey {
+    fn from(import_key: ImportKey) -> Self {
+        Self {
+            network: Some(import_key.connection_config),
+            account_id: Some(import_key.account_id),
+            key: import_key.key.into(),
+        }
+    }
+}
+
+impl ImportKey {
+    pub fn from(item: CliImportKey) -> color_eyre::eyre::Result<Self> {
+        let connection_config = match item.network {
+            Some(connection_config) => connection_config,
+            None => crate::common::ConnectionConfig::choose_network("Select the network")?,
+        };
+        let account_id = match item.account_id {
+            Some(account_id) => account_id,
+            None => ImportKey::input_account_id()?,
+        };
+        Ok(Self {
+            connection_config,
+            account_id,
+            key: ImportedKey::from(item.key)?,
+        })
+    }
+}
+
+impl ImportKey {
+    fn input_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        crate::common::ensure_interactive("account-id")?;
+        Ok(dialoguer::Input::new()
+            .with_prompt("What is the account ID of the key?")
+            .interact_text()
+            .unwrap())
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        self.key
+            .key_pair()?
+            .save(self.connection_config, &self.account_id.to_string())
+            .await
+    }
+}
+
+impl CliImportedKey {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.seed_phrase_passphrase.to_cli_args();
+        if let Some(key_type) = &self.key_type {
+            args.push_front(key_type.to_string());
+            args.push_front("--key-type".to_owned());
+        }
+        if let Some(seed_phrase_hd_path) = &self.seed_phrase_hd_path {
+            args.push_front(seed_phrase_hd_path.to_string());
+            args.push_front("--seed-phrase-hd-path".to_owned());
+        }
+        if let Some(seed_phrase) = &self.seed_phrase {
+            args.push_front(seed_phrase.to_string());
+            args.push_front("--seed-phrase".to_owned());
+        }
+        if let Some(private_key) = &self.private_key {
+            args.push_front(private_key.to_string());
+            args.push_front("--private-key".to_owned());
+        }
+        args
+    }
+}
+
+impl From<ImportedKey> for CliImportedKey {
+    fn from(imported_key: ImportedKey) -> Self {
+        match imported_key {
+            ImportedKey::PrivateKey(private_key) => Self {
+                private_key: Some(private_key),
+                ..Default::default()
+            },
+            ImportedKey::SeedPhrase {
+                seed_phrase,
+                seed_phrase_hd_path,
+                key_type,
+                seed_phrase_passphrase,
+            } => Self {
+                seed_phrase: Some(seed_phrase),
+                seed_phrase_hd_path: Some(seed_phrase_hd_path),
+                key_type: Some(key_type),
+                seed_phrase_passphrase,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl ImportedKey {
+    pub fn from(item: CliImportedKey) -> color_eyre::eyre::Result<Self> {
+        match (item.private_key, item.seed_phrase) {
+            (Some(private_key), _) => Ok(Self::PrivateKey(private_key)),
+            (None, Some(seed_phrase)) => Ok(Self::SeedPhrase {
+                seed_phrase,
+                seed_phrase_hd_path: item
+                    .seed_phrase_hd_path
+                    .unwrap_or_else(ImportedKey::default_seed_phrase_hd_path),
+                key_type: item.key_type.unwrap_or(near_crypto::KeyType::ED25519),
+                seed_phrase_passphrase: item.seed_phrase_passphrase,
+            }),
+            (None, None) => Self::input_key(),
+        }
+    }
+}
+
+impl ImportedKey {
+    fn default_seed_phrase_hd_path() -> slip10::BIP32Path {
+        slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap()
+    }
+
+    fn input_key() -> color_eyre::eyre::Result<Self> {
+        crate::common::ensure_interactive("private-key")?;
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("How is the key given?")
+            .items(&["Private key", "Seed phrase"])
+            .default(0)
+            .interact()
+            .unwrap();
+        Ok(match selection {
+            0 => Self::PrivateKey(
+                crate::common::input_secret::<near_crypto::SecretKey>("Enter the private key")
+                    .into(),
+            ),
+            _ => Self::SeedPhrase {
+                seed_phrase: crate::common::input_secret::<String>("Enter the seed phrase").into(),
+                seed_phrase_hd_path: dialoguer::Input::new()
+                    .with_prompt(
+                        "Enter seed phrase HD Path (if you not sure leave blank for default)",
+                    )
+                    .with_initial_text("m/44'/397'/0'")
+                    .interact_text()
+                    .unwrap(),
+                key_type: crate::common::input_key_type()?,
+                seed_phrase_passphrase: Self::input_seed_phrase_passphrase(),
+            },
+        })
+    }
+
+    fn input_seed_phrase_passphrase() -> crate::common::CliSeedPhrasePassphrase {
+        let protected = dialoguer::Confirm::new()
+            .with_prompt("Is the seed phrase protected with a BIP-39 passphrase?")
+            .default(false)
+            .interact()
+            .unwrap();
+        crate::common::CliSeedPhrasePassphrase {
+            seed_phrase_passphrase: None,
+            ask_seed_phrase_passphrase: protected,
+        }
+    }
+
+    /// Derives the key pair from the seed phrase, asking for its passphrase if needed.
+    pub fn key_pair(self) -> color_eyre::eyre::Result<ImportedKeyPair> {
+        match self {
+            Self::PrivateKey(private_key) => Ok(ImportedKeyPair::PrivateKey(private_key.inner)),
+            Self::SeedPhrase {
+                seed_phrase,
+                seed_phrase_hd_path,
+                key_type,
+                seed_phrase_passphrase,
+            } => Ok(ImportedKeyPair::SeedPhrase(
+                crate::common::get_key_pair_properties_from_seed_phrase(
+                    seed_phrase.inner,
+                    seed_phrase_hd_path,
+                    key_type,
+                    &seed_phrase_passphrase.passphrase(false)?,
+                )?,
+            )),
+        }
+    }
+}
+
+impl ImportedKeyPair {
+    pub fn public_key(&self) -> near_crypto::PublicKey {
+        match self {
+            Self::PrivateKey(private_key) => private_key.public_key(),
+            Self::SeedPhrase(key_pair_properties) => {
+                near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)
+                    .expect("A derived public key is valid")
+            }
+        }
+    }
+
+    /// The implicit account of the key; only ed25519 keys have one.
+    pub fn implicit_account_id(&self) -> Option<near_primitives::types::AccountId> {
+        match self.public_key() {
+            near_crypto::PublicKey::ED25519(public_key) => hex::encode(&public_key.0).parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Saves the key for the account to the keychain directory of the network.
+    pub async fn save(
+        self,
+        connection_config: crate::common::ConnectionConfig,
+        account_id: &str,
+    ) -> crate::CliResult {
+        match self {
+            Self::PrivateKey(private_key) => {
+                let public_key = private_key.public_key().to_string();
+                let key_file = serde_json::json!({
+                    "account_id": account_id,
+                    "public_key": public_key,
+                    "private_key": private_key.to_string(),
+                });
+                crate::common::save_key_file_to_keychain(
+                    Some(connection_config),
+                    &key_file,
+                    account_id,
+                    &public_key,
+                )
+            }
+            Self::SeedPhrase(key_pair_properties) => {
+                crate::common::save_access_key_to_keychain(
+                    Some(connection_config),
+                    key_pair_properties,
+                    account_id,
+                )
+                .await
+            }
+        }
+    }
+}
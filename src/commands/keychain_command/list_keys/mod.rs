@@ -0,0 +1,69 @@
+/// список ключей, сохранённых в хранилище
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliListKeys {
+    /// имя сети из конфигурационного файла; без него выводятся ключи всех сетей
+    network: Option<crate::common::ConnectionConfig>,
+}
+
+impl CliListKeys {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(network) = &self.network {
+            args.push_front(network.to_string());
+        }
+        args
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let connection_configs = match self.network {
+            Some(connection_config) => vec![connection_config],
+            None => crate::common::ConnectionConfig::preselected_or_all_networks()?,
+        };
+        let mut json_keys = vec![];
+        // The legacy directory is in the search path of every network, it is listed once
+        let mut listed_dirs = vec![];
+        for connection_config in connection_configs {
+            println!("\nNetwork <{}>:", connection_config.network_name());
+            let mut has_keys = false;
+            for credentials_dir in
+                crate::keychain::credentials_search_path(Some(&connection_config))?
+            {
+                if listed_dirs.contains(&credentials_dir) {
+                    continue;
+                }
+                let keys = crate::keychain::list_keys(&credentials_dir)?;
+                if !keys.is_empty() {
+                    println!("  {}:", credentials_dir.display());
+                    has_keys = true;
+                }
+                for key in keys {
+                    println!(
+                        "    {:<40} {}{}",
+                        key.account_id,
+                        key.public_key,
+                        if key.encrypted { " (encrypted)" } else { "" }
+                    );
+                    json_keys.push(serde_json::json!({
+                        "network": connection_config.network_name(),
+                        "account_id": key.account_id,
+                        "public_key": key.public_key,
+                        "encrypted": key.encrypted,
+                        "credentials_dir": credentials_dir,
+                        "file": key.path,
+                    }));
+                }
+                listed_dirs.push(credentials_dir);
+            }
+            if !has_keys {
+                println!("  no keys");
+            }
+        }
+        crate::common::add_json_output("keys", serde_json::json!(json_keys));
+        Ok(())
+    }
+}
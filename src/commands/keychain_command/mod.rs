@@ -0,0 +1,289 @@
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod audit_keys;
+mod export_keys;
+pub(crate) mod import_key;
+mod list_keys;
+mod remove_key;
+mod show_key;
+
+/// управление ключами в ~/.near-credentials
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliKeychain {
+    #[clap(subcommand)]
+    action: Option<CliKeychainAction>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Keychain {
+    pub action: KeychainAction,
+}
+
+impl CliKeychain {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.action
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default()
+    }
+}
+
+impl From<Keychain> for CliKeychain {
+    fn from(keychain: Keychain) -> Self {
+        Self {
+            action: Some(keychain.action.into()),
+        }
+    }
+}
+
+impl Keychain {
+    pub fn from(item: CliKeychain) -> color_eyre::eyre::Result<Self> {
+        let action = match item.action {
+            Some(cli_action) => KeychainAction::from(cli_action)?,
+            None => KeychainAction::choose_action()?,
+        };
+        Ok(Self { action })
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        self.action.process().await
+    }
+}
+
+#[derive(Debug, Clone, clap::Clap)]
+enum CliKeychainAction {
+    /// List the accounts and keys saved for each network
+    List(self::list_keys::CliListKeys),
+    /// Show the public key and the seed phrase HD path of a key (the secret only when asked)
+    Show(self::show_key::CliShowKey),
+    /// Save a key given as a private key or a seed phrase
+    Import(self::import_key::CliImportKey),
+    /// Save the keys to a backup bundle file
+    Export(self::export_keys::CliExportKeys),
+    /// Remove a key
+    Remove(self::remove_key::CliRemoveKey),
+    /// Check the permissions of the key files, plaintext seed phrases and keys removed on-chain
+    Audit(self::audit_keys::CliAuditKeys),
+}
+
+#[derive(Debug, Clone, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum KeychainAction {
+    #[strum_discriminants(strum(message = "List the saved keys"))]
+    List(self::list_keys::CliListKeys),
+    #[strum_discriminants(strum(message = "Show a key"))]
+    Show(self::show_key::ShowKey),
+    #[strum_discriminants(strum(message = "Import a key from a private key or a seed phrase"))]
+    Import(self::import_key::ImportKey),
+    #[strum_discriminants(strum(message = "Export the keys to a backup bundle"))]
+    Export(self::export_keys::ExportKeys),
+    #[strum_discriminants(strum(message = "Remove a key"))]
+    Remove(self::remove_key::RemoveKey),
+    #[strum_discriminants(strum(message = "Audit the saved keys"))]
+    Audit(self::audit_keys::CliAuditKeys),
+}
+
+impl CliKeychainAction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::List(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("list".to_owned());
+                args
+            }
+            Self::Show(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("show".to_owned());
+                args
+            }
+            Self::Import(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("import".to_owned());
+                args
+            }
+            Self::Export(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("export".to_owned());
+                args
+            }
+            Self::Remove(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("remove".to_owned());
+                args
+            }
+            Self::Audit(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("audit".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<KeychainAction> for CliKeychainAction {
+    fn from(keychain_action: KeychainAction) -> Self {
+        match keychain_action {
+            KeychainAction::List(list_keys) => Self::List(list_keys),
+            KeychainAction::Show(show_key) => Self::Show(show_key.into()),
+            KeychainAction::Import(import_key) => Self::Import(import_key.into()),
+            KeychainAction::Export(export_keys) => Self::Export(export_keys.into()),
+            KeychainAction::Remove(remove_key) => Self::Remove(remove_key.into()),
+            KeychainAction::Audit(audit_keys) => Self::Audit(audit_keys),
+        }
+    }
+}
+
+impl KeychainAction {
+    fn from(item: CliKeychainAction) -> color_eyre::eyre::Result<Self> {
+        Ok(match item {
+            CliKeychainAction::List(list_keys) => Self::List(list_keys),
+            CliKeychainAction::Show(cli_show_key) => {
+                Self::Show(self::show_key::ShowKey::from(cli_show_key)?)
+            }
+            CliKeychainAction::Import(cli_import_key) => {
+                Self::Import(self::import_key::ImportKey::from(cli_import_key)?)
+            }
+            CliKeychainAction::Export(cli_export_keys) => {
+                Self::Export(self::export_keys::ExportKeys::from(cli_export_keys)?)
+            }
+            CliKeychainAction::Remove(cli_remove_key) => {
+                Self::Remove(self::remove_key::RemoveKey::from(cli_remove_key)?)
+            }
+            CliKeychainAction::Audit(audit_keys) => Self::Audit(audit_keys),
+        })
+    }
+
+    fn choose_action() -> color_eyre::eyre::Result<Self> {
+        println!();
+        let variants = KeychainActionDiscriminants::iter().collect::<Vec<_>>();
+        let actions = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        crate::common::ensure_interactive("action")?;
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Choose your action")
+            .items(&actions)
+            .default(0)
+            .interact()?;
+        let cli_action = match variants[selection] {
+            KeychainActionDiscriminants::List => CliKeychainAction::List(Default::default()),
+            KeychainActionDiscriminants::Show => CliKeychainAction::Show(Default::default()),
+            KeychainActionDiscriminants::Import => CliKeychainAction::Import(Default::default()),
+            KeychainActionDiscriminants::Export => CliKeychainAction::Export(Default::default()),
+            KeychainActionDiscriminants::Remove => CliKeychainAction::Remove(Default::default()),
+            KeychainActionDiscriminants::Audit => CliKeychainAction::Audit(Default::default()),
+        };
+        Self::from(cli_action)
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        match self {
+            Self::List(list_keys) => list_keys.process().await,
+            Self::Show(show_key) => show_key.process().await,
+            Self::Import(import_key) => import_key.process().await,
+            Self::Export(export_keys) => export_keys.process().await,
+            Self::Remove(remove_key) => remove_key.process().await,
+            Self::Audit(audit_keys) => audit_keys.process().await,
+        }
+    }
+}
+
+/// The keys of the network from every directory they are read from when signing.
+fn network_keys(
+    connection_config: &crate::common::ConnectionConfig,
+) -> color_eyre::eyre::Result<Vec<crate::keychain::KeychainKey>> {
+    crate::keychain::find_keys(&crate::keychain::credentials_search_path(Some(
+        connection_config,
+    ))?)
+}
+
+/// Picks an account that has keys saved for the network.
+pub(crate) fn input_account_id(
+    connection_config: &crate::common::ConnectionConfig,
+) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+    let mut account_ids = network_keys(connection_config)?
+        .into_iter()
+        .map(|key| key.account_id)
+        .collect::<Vec<_>>();
+    account_ids.sort();
+    account_ids.dedup();
+    if account_ids.is_empty() {
+        return Err(color_eyre::Report::msg(format!(
+            "There are no keys for network <{}> in the keychain",
+            connection_config.network_name()
+        )));
+    }
+    crate::common::ensure_interactive("account-id")?;
+    let selection = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt("Select the account")
+        .items(&account_ids)
+        .default(0)
+        .interact()
+        .unwrap();
+    let account_id = account_ids.swap_remove(selection);
+    account_id.parse().map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "<{}> is not a valid account ID: {}",
+            account_id, err
+        ))
+    })
+}
+
+/// Picks one of the keys saved for the account.
+fn choose_public_key(
+    connection_config: &crate::common::ConnectionConfig,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+    let key = find_key(connection_config, &account_id.to_string(), None)?;
+    key.public_key.parse().map_err(|err| {
+        color_eyre::Report::msg(format!("Invalid public key {}: {:?}", key.public_key, err))
+    })
+}
+
+/// The key of the account with the given public key, or the only one, or the one picked in a prompt.
+fn find_key(
+    connection_config: &crate::common::ConnectionConfig,
+    account_id: &str,
+    public_key: Option<&near_crypto::PublicKey>,
+) -> color_eyre::eyre::Result<crate::keychain::KeychainKey> {
+    let mut keys = network_keys(connection_config)?
+        .into_iter()
+        .filter(|key| key.account_id == account_id)
+        .filter(|key| match public_key {
+            Some(public_key) => key.public_key == public_key.to_string(),
+            None => true,
+        })
+        .collect::<Vec<_>>();
+    match keys.len() {
+        0 => Err(color_eyre::Report::msg(format!(
+            "There is no {} of <{}> for network <{}> in the keychain",
+            public_key
+                .map(|public_key| format!("key {}", public_key))
+                .unwrap_or_else(|| "key".to_owned()),
+            account_id,
+            connection_config.network_name()
+        ))),
+        1 => Ok(keys.remove(0)),
+        _ => {
+            crate::common::ensure_interactive("public-key")?;
+            let selection = Select::with_theme(&ColorfulTheme::default())
+                .with_prompt("Select the key")
+                .items(
+                    &keys
+                        .iter()
+                        .map(|key| format!("{} ({})", key.public_key, key.path.display()))
+                        .collect::<Vec<_>>(),
+                )
+                .default(0)
+                .interact()?;
+            Ok(keys.swap_remove(selection))
+        }
+    }
+}
@@ -0,0 +1,114 @@
+/// удаление ключа из хранилища
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliRemoveKey {
+    /// имя сети из конфигурационного файла (например, testnet или mainnet)
+    network: Option<crate::common::ConnectionConfig>,
+    #[clap(long)]
+    account_id: Option<near_primitives::types::AccountId>,
+    /// нужен, если для аккаунта сохранено несколько ключей
+    #[clap(long)]
+    public_key: Option<near_crypto::PublicKey>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoveKey {
+    connection_config: crate::common::ConnectionConfig,
+    account_id: near_primitives::types::AccountId,
+    public_key: near_crypto::PublicKey,
+}
+
+impl CliRemoveKey {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(public_key) = &self.public_key {
+            args.push_front(public_key.to_string());
+            args.push_front("--public-key".to_owned());
+        }
+        if let Some(account_id) = &self.account_id {
+            args.push_front(account_id.to_string());
+            args.push_front("--account-id".to_owned());
+        }
+        if let Some(network) = &self.network {
+            args.push_front(network.to_string());
+        }
+        args
+    }
+}
+
+impl From<RemoveKey> for CliRemoveKey {
+    fn from(remove_key: RemoveKey) -> Self {
+        Self {
+            network: Some(remove_key.connection_config),
+            account_id: Some(remove_key.account_id),
+            public_key: Some(remove_key.public_key),
+        }
+    }
+}
+
+impl RemoveKey {
+    pub fn from(item: CliRemoveKey) -> color_eyre::eyre::Result<Self> {
+        let connection_config = match item.network {
+            Some(connection_config) => connection_config,
+            None => crate::common::ConnectionConfig::choose_network("Select the network")?,
+        };
+        let account_id = match item.account_id {
+            Some(account_id) => account_id,
+            None => super::input_account_id(&connection_config)?,
+        };
+        // The key is picked here, so the echoed command removes exactly this key
+        let public_key = match item.public_key {
+            Some(public_key) => public_key,
+            None => super::choose_public_key(&connection_config, &account_id)?,
+        };
+        Ok(Self {
+            connection_config,
+            account_id,
+            public_key,
+        })
+    }
+}
+
+impl RemoveKey {
+    pub async fn process(self) -> crate::CliResult {
+        // The key is removed from every directory it is read from when signing
+        let mut removed_paths = vec![];
+        for credentials_dir in
+            crate::keychain::credentials_search_path(Some(&self.connection_config))?
+        {
+            removed_paths.extend(crate::keychain::remove_key(
+                &credentials_dir,
+                &self.account_id.to_string(),
+                &self.public_key.to_string(),
+            )?);
+        }
+        if removed_paths.is_empty() {
+            return Err(color_eyre::Report::msg(format!(
+                "There is no key {} of <{}> for network <{}> in the keychain",
+                self.public_key,
+                self.account_id,
+                self.connection_config.network_name()
+            )));
+        }
+        println!(
+            "\nThe key {} of <{}> has been removed:",
+            self.public_key, self.account_id
+        );
+        for path in removed_paths.iter() {
+            println!("  {}", path.display());
+        }
+        crate::common::add_json_output(
+            "removed_key",
+            serde_json::json!({
+                "account_id": self.account_id,
+                "public_key": self.public_key,
+                "files": removed_paths,
+            }),
+        );
+        Ok(())
+    }
+}
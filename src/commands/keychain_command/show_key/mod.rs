@@ -0,0 +1,141 @@
+/// просмотр ключа, сохранённого в хранилище
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliShowKey {
+    /// имя сети из конфигурационного файла (например, testnet или mainnet)
+    network: Option<crate::common::ConnectionConfig>,
+    #[clap(long)]
+    account_id: Option<near_primitives::types::AccountId>,
+    /// нужен, если для аккаунта сохранено несколько ключей
+    #[clap(long)]
+    public_key: Option<near_crypto::PublicKey>,
+    /// показать приватный ключ и seed-фразу
+    #[clap(long)]
+    show_secret: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ShowKey {
+    connection_config: crate::common::ConnectionConfig,
+    account_id: near_primitives::types::AccountId,
+    public_key: near_crypto::PublicKey,
+    show_secret: bool,
+}
+
+impl CliShowKey {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if self.show_secret {
+            args.push_front("--show-secret".to_owned());
+        }
+        if let Some(public_key) = &self.public_key {
+            args.push_front(public_key.to_string());
+            args.push_front("--public-key".to_owned());
+        }
+        if let Some(account_id) = &self.account_id {
+            args.push_front(account_id.to_string());
+            args.push_front("--account-id".to_owned());
+        }
+        if let Some(network) = &self.network {
+            args.push_front(network.to_string());
+        }
+        args
+    }
+}
+
+impl From<ShowKey> for CliShowKey {
+    fn from(show_key: ShowKey) -> Self {
+        Self {
+            network: Some(show_key.connection_config),
+            account_id: Some(show_key.account_id),
+            public_key: Some(show_key.public_key),
+            show_secret: show_key.show_secret,
+        }
+    }
+}
+
+impl ShowKey {
+    pub fn from(item: CliShowKey) -> color_eyre::eyre::Result<Self> {
+        let connection_config = match item.network {
+            Some(connection_config) => connection_config,
+            None => crate::common::ConnectionConfig::choose_network("Select the network")?,
+        };
+        let account_id = match item.account_id {
+            Some(account_id) => account_id,
+            None => super::input_account_id(&connection_config)?,
+        };
+        let public_key = match item.public_key {
+            Some(public_key) => public_key,
+            None => super::choose_public_key(&connection_config, &account_id)?,
+        };
+        Ok(Self {
+            connection_config,
+            account_id,
+            public_key,
+            show_secret: item.show_secret,
+        })
+    }
+}
+
+impl ShowKey {
+    pub async fn process(self) -> crate::CliResult {
+        let key = super::find_key(
+            &self.connection_config,
+            &self.account_id.to_string(),
+            Some(&self.public_key),
+        )?;
+        // Encrypted key files are decrypted only to show the secret
+        let key_file = if self.show_secret || !key.encrypted {
+            Some(crate::keychain::read_key_file(&key.path)?)
+        } else {
+            None
+        };
+        let field = |name: &str| {
+            key_file
+                .as_ref()
+                .and_then(|key_file| key_file[name].as_str())
+                .map(str::to_owned)
+        };
+        println!();
+        println!("{:<20} {}", "Account ID:", key.account_id);
+        println!("{:<20} {}", "Public key:", key.public_key);
+        println!(
+            "{:<20} {}",
+            "Seed phrase HD path:",
+            match (field("seed_phrase_hd_path"), key.encrypted) {
+                (Some(seed_phrase_hd_path), _) => seed_phrase_hd_path,
+                (None, true) => "(encrypted)".to_owned(),
+                (None, false) => "-".to_owned(),
+            }
+        );
+        println!("{:<20} {}", "File:", key.path.display());
+        println!("{:<20} {}", "Encrypted:", key.encrypted);
+        let mut json_key = serde_json::json!({
+            "account_id": key.account_id,
+            "public_key": key.public_key,
+            "seed_phrase_hd_path": field("seed_phrase_hd_path"),
+            "file": key.path,
+            "encrypted": key.encrypted,
+        });
+        if self.show_secret {
+            println!(
+                "{:<20} {}",
+                "Master seed phrase:",
+                field("master_seed_phrase").unwrap_or_else(|| "-".to_owned())
+            );
+            println!(
+                "{:<20} {}",
+                "Private key:",
+                field("private_key").unwrap_or_else(|| "-".to_owned())
+            );
+            json_key["master_seed_phrase"] = serde_json::json!(field("master_seed_phrase"));
+            json_key["private_key"] = serde_json::json!(field("private_key"));
+        }
+        crate::common::add_json_output("key", json_key);
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use crate::commands::keychain_command::import_key::{CliImportedKey, ImportedKey};
+
+/// вход с ключом, заданным приватным ключом или seed-фразой
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliImportKeyMode {
+    /// имя сети из конфигурационного файла (например, testnet или mainnet)
+    network: Option<crate::common::ConnectionConfig>,
+    /// the named account of the key (the implicit account of an ed25519 key is looked up anyway)
+    #[clap(long)]
+    account_id: Option<near_primitives::types::AccountId>,
+    #[clap(flatten)]
+    key: CliImportedKey,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportKeyMode {
+    connection_config: crate::common::ConnectionConfig,
+    account_id: Option<near_primitives::types::AccountId>,
+    key: ImportedKey,
+}
+
+impl CliImportKeyMode {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.key.to_cli_args();
+        if let Some(account_id) = &self.account_id {
+            args.push_front(account_id.to_string());
+            args.push_front("--account-id".to_owned());
+        }
+        if let Some(network) = &self.network {
+            args.push_front(network.to_string());
+        }
+        args
+    }
+}
+
+impl From<ImportKeyMode> for CliImportKeyMode {
+    fn from(import_key_mode: ImportKeyMode) -> Self {
+        Self {
+            network: Some(import_key_mode.connection_config),
+            account_id: import_key_mode.account_id,
+            key: import_key_mode.key.into(),
+        }
+    }
+}
+
+impl ImportKeyMode {
+    pub fn from(item: CliImportKeyMode) -> color_eyre::eyre::Result<Self> {
+        let connection_config = match item.network {
+            Some(connection_config) => connection_config,
+            None => crate::common::ConnectionConfig::choose_network("Select the network")?,
+        };
+        Ok(Self {
+            connection_config,
+            account_id: item.account_id,
+            key: ImportedKey::from(item.key)?,
+        })
+    }
+}
+
+impl ImportKeyMode {
+    pub async fn process(self) -> crate::CliResult {
+        let key_pair = self.key.key_pair()?;
+        let public_key = key_pair.public_key();
+        println!("The public key of the imported key is {}", public_key);
+        let account_ids = super::accounts_with_key(
+            &public_key,
+            key_pair.implicit_account_id(),
+            self.account_id,
+            &self.connection_config,
+        )
+        .await?;
+        for account_id in account_ids {
+            key_pair
+                .clone()
+                .save(self.connection_config.clone(), &account_id.to_string())
+                .await
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "Failed to save a file with access key: {}",
+                        err
+                    ))
+                })?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+/// вход с ключом на устройстве Ledger
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliLedgerMode {
+    /// имя сети из конфигурационного файла (например, testnet или mainnet)
+    network: Option<crate::common::ConnectionConfig>,
+    /// HD path of the key on the Ledger device (44'/397'/0'/0'/1' by default)
+    #[clap(long)]
+    seed_phrase_hd_path: Option<slip10::BIP32Path>,
+    /// the named account of the key (the implicit account of the key is looked up anyway)
+    #[clap(long)]
+    account_id: Option<near_primitives::types::AccountId>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LedgerMode {
+    connection_config: crate::common::ConnectionConfig,
+    seed_phrase_hd_path: slip10::BIP32Path,
+    account_id: Option<near_primitives::types::AccountId>,
+}
+
+impl CliLedgerMode {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(account_id) = &self.account_id {
+            args.push_front(account_id.to_string());
+            args.push_front("--account-id".to_owned());
+        }
+        if let Some(seed_phrase_hd_path) = &self.seed_phrase_hd_path {
+            args.push_front(seed_phrase_hd_path.to_string());
+            args.push_front("--seed-phrase-hd-path".to_owned());
+        }
+        if let Some(network) = &self.network {
+            args.push_front(network.to_string());
+        }
+        args
+    }
+}
+
+impl From<LedgerMode> for CliLedgerMode {
+    fn from(ledger_mode: LedgerMode) -> Self {
+        Self {
+            network: Some(ledger_mode.connection_config),
+            seed_phrase_hd_path: Some(ledger_mode.seed_phrase_hd_path),
+            account_id: ledger_mode.account_id,
+        }
+    }
+}
+
+impl LedgerMode {
+    pub fn from(item: CliLedgerMode) -> color_eyre::eyre::Result<Self> {
+        let connection_config = match item.network {
+            Some(connection_config) => connection_config,
+            None => crate::common::ConnectionConfig::choose_network("Select the network")?,
+        };
+        let seed_phrase_hd_path = match item.seed_phrase_hd_path {
+            Some(seed_phrase_hd_path) => seed_phrase_hd_path,
+            None => crate::commands::construct_transaction_command::sign_transaction::sign_with_ledger::SignLedger::input_seed_phrase_hd_path()?,
+        };
+        Ok(Self {
+            connection_config,
+            seed_phrase_hd_path,
+            account_id: item.account_id,
+        })
+    }
+}
+
+impl LedgerMode {
+    pub async fn process(self) -> crate::CliResult {
+        println!(
+            "Please allow getting the PublicKey on Ledger device (HD Path: {})",
+            self.seed_phrase_hd_path
+        );
+        let public_key = near_ledger::get_public_key(self.seed_phrase_hd_path.clone())
+            .await
+            .map_err(|near_ledger_error| {
+                color_eyre::Report::msg(format!(
+                    "An error occurred while trying to get PublicKey from Ledger device: {:?}",
+                    near_ledger_error
+                ))
+            })?;
+        let implicit_account_id = hex::encode(&public_key).parse().ok();
+        let public_key = near_crypto::PublicKey::ED25519(near_crypto::ED25519PublicKey::from(
+            public_key.to_bytes(),
+        ));
+        println!("The public key of the Ledger key is {}", public_key);
+        let account_ids = super::accounts_with_key(
+            &public_key,
+            implicit_account_id,
+            self.account_id,
+            &self.connection_config,
+        )
+        .await?;
+        for account_id in account_ids {
+            let account_id = account_id.to_string();
+            let key_file = ledger_key_file(&account_id, &public_key, &self.seed_phrase_hd_path);
+            crate::common::save_key_file_to_keychain(
+                Some(self.connection_config.clone()),
+                &key_file,
+                &account_id,
+                &public_key.to_string(),
+            )
+            .map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to save a file with access key: {}", err))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// The secret stays on the device: the key file only records where to find it, and
+/// `sign-with-keychain` signs with the Ledger device when it reads such a file.
+pub(crate) fn ledger_key_file(
+    account_id: &str,
+    public_key: &near_crypto::PublicKey,
+    seed_phrase_hd_path: &slip10::BIP32Path,
+) -> serde_json::Value {
+    serde_json::json!({
+        "account_id": account_id,
+        "public_key": public_key.to_string(),
+        "seed_phrase_hd_path": seed_phrase_hd_path.to_string(),
+        "ledger": true,
+    })
+}
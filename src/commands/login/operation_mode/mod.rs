@@ -1,5 +1,8 @@
-use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
+pub mod import_key_mode;
+pub mod ledger_mode;
 pub mod online_mode;
 
 /// инструмент выбора режима online/offline
@@ -36,13 +39,13 @@ impl From<OperationMode> for CliOperationMode {
     }
 }
 
-impl From<CliOperationMode> for OperationMode {
-    fn from(item: CliOperationMode) -> Self {
+impl OperationMode {
+    pub fn from(item: CliOperationMode) -> color_eyre::eyre::Result<Self> {
         let mode = match item.mode {
-            Some(cli_mode) => Mode::from(cli_mode),
-            None => Mode::choose_mode(),
+            Some(cli_mode) => Mode::from(cli_mode)?,
+            None => Mode::choose_mode()?,
         };
-        Self { mode }
+        Ok(Self { mode })
     }
 }
 
@@ -56,13 +59,23 @@ impl OperationMode {
 pub enum CliMode {
     /// Execute a change method with online mode
     Network(self::online_mode::CliNetworkArgs),
+    /// Log in with a seed phrase or a private key of an existing access key
+    ImportKey(self::import_key_mode::CliImportKeyMode),
+    /// Log in with a key kept on a Ledger device
+    Ledger(self::ledger_mode::CliLedgerMode),
 }
 
 #[derive(Debug, Clone, EnumDiscriminants)]
 #[strum_discriminants(derive(EnumMessage, EnumIter))]
 pub enum Mode {
-    #[strum_discriminants(strum(message = "Yes, I keep it simple"))]
+    #[strum_discriminants(strum(message = "Add a new key with the wallet in the browser"))]
     Network(self::online_mode::NetworkArgs),
+    #[strum_discriminants(strum(
+        message = "Import a seed phrase or a private key I already have"
+    ))]
+    ImportKey(self::import_key_mode::ImportKeyMode),
+    #[strum_discriminants(strum(message = "Use a key on my Ledger device"))]
+    Ledger(self::ledger_mode::LedgerMode),
 }
 
 impl CliMode {
@@ -73,6 +86,16 @@ impl CliMode {
                 args.push_front("network".to_owned());
                 args
             }
+            Self::ImportKey(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("import-key".to_owned());
+                args
+            }
+            Self::Ledger(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("ledger".to_owned());
+                args
+            }
         }
     }
 }
@@ -83,26 +106,158 @@ impl From<Mode> for CliMode {
             Mode::Network(network_args) => {
                 Self::Network(self::online_mode::CliNetworkArgs::from(network_args))
             }
+            Mode::ImportKey(import_key_mode) => Self::ImportKey(import_key_mode.into()),
+            Mode::Ledger(ledger_mode) => Self::Ledger(ledger_mode.into()),
         }
     }
 }
 
-impl From<CliMode> for Mode {
-    fn from(item: CliMode) -> Self {
+impl Mode {
+    fn from(item: CliMode) -> color_eyre::eyre::Result<Self> {
         match item {
-            CliMode::Network(cli_network_args) => Self::Network(cli_network_args.into()),
+            CliMode::Network(cli_network_args) => Ok(Self::Network(
+                self::online_mode::NetworkArgs::from(cli_network_args)?,
+            )),
+            CliMode::ImportKey(cli_import_key_mode) => Ok(Self::ImportKey(
+                self::import_key_mode::ImportKeyMode::from(cli_import_key_mode)?,
+            )),
+            CliMode::Ledger(cli_ledger_mode) => Ok(Self::Ledger(
+                self::ledger_mode::LedgerMode::from(cli_ledger_mode)?,
+            )),
         }
     }
 }
 
 impl Mode {
-    pub fn choose_mode() -> Self {
-        Self::from(CliMode::Network(Default::default()))
+    pub fn choose_mode() -> color_eyre::eyre::Result<Self> {
+        println!();
+        let variants = ModeDiscriminants::iter().collect::<Vec<_>>();
+        let modes = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        crate::common::ensure_interactive("mode")?;
+        let selected_mode = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("How do you want to log in?")
+            .items(&modes)
+            .default(0)
+            .interact()
+            .unwrap();
+        let cli_mode = match variants[selected_mode] {
+            ModeDiscriminants::Network => CliMode::Network(Default::default()),
+            ModeDiscriminants::ImportKey => CliMode::ImportKey(Default::default()),
+            ModeDiscriminants::Ledger => CliMode::Ledger(Default::default()),
+        };
+        Self::from(cli_mode)
     }
 
     pub async fn process(self) -> crate::CliResult {
         match self {
             Self::Network(network_args) => network_args.process().await,
+            Self::ImportKey(import_key_mode) => import_key_mode.process().await,
+            Self::Ledger(ledger_mode) => ledger_mode.process().await,
+        }
+    }
+}
+
+/// The accounts that have the key: its implicit account, if the key is there, and the named
+/// account given on the command line or asked for; the named account must have the key. Without
+/// prompts the named account is only required when the implicit account does not have the key.
+async fn accounts_with_key(
+    public_key: &near_crypto::PublicKey,
+    implicit_account_id: Option<near_primitives::types::AccountId>,
+    account_id: Option<near_primitives::types::AccountId>,
+    connection_config: &crate::common::ConnectionConfig,
+) -> color_eyre::eyre::Result<Vec<near_primitives::types::AccountId>> {
+    let mut account_ids = Vec::new();
+    if let Some(implicit_account_id) = implicit_account_id {
+        if has_access_key(&implicit_account_id, public_key, connection_config).await? {
+            println!("The implicit account <{}> has the key", implicit_account_id);
+            account_ids.push(implicit_account_id);
+        } else {
+            println!(
+                "The implicit account <{}> does not have the key",
+                implicit_account_id
+            );
+        }
+    }
+    let account_id = match account_id {
+        Some(account_id) => Some(account_id),
+        None if account_ids.is_empty() || !crate::common::prompts_disabled() => {
+            input_account_id(!account_ids.is_empty())?
+        }
+        None => None,
+    };
+    if let Some(account_id) = account_id {
+        if !account_ids.contains(&account_id) {
+            self::online_mode::select_server::server::verify_account_id(
+                account_id.clone(),
+                public_key.clone(),
+                connection_config.clone(),
+            )
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "The key {} is not an access key of <{}>: {}",
+                    public_key, account_id, err
+                ))
+            })?;
+            println!("The account <{}> has the key", account_id);
+            account_ids.push(account_id);
+        }
+    }
+    Ok(account_ids)
+}
+
+/// An unknown account or key means the account does not have the key; other errors are returned.
+async fn has_access_key(
+    account_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+    connection_config: &crate::common::ConnectionConfig,
+) -> color_eyre::eyre::Result<bool> {
+    match connection_config
+        .rpc_client()
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request: near_primitives::views::QueryRequest::ViewAccessKey {
+                account_id: account_id.clone(),
+                public_key: public_key.clone(),
+            },
+        })
+        .await
+    {
+        Ok(_) => Ok(true),
+        Err(err) => match crate::rpc_client::handler_error_cause(&err) {
+            Some("UNKNOWN_ACCOUNT") | Some("UNKNOWN_ACCESS_KEY") => Ok(false),
+            _ => Err(color_eyre::Report::msg(format!(
+                "Failed to fetch query for view access key: {:?}",
+                err
+            ))),
+        },
+    }
+}
+
+fn input_account_id(
+    optional: bool,
+) -> color_eyre::eyre::Result<Option<near_primitives::types::AccountId>> {
+    crate::common::ensure_interactive("account-id")?;
+    let account_id: String = dialoguer::Input::new()
+        .with_prompt(if optional {
+            "Enter the named account ID of the key (leave blank to skip)"
+        } else {
+            "Enter the account ID of the key"
+        })
+        .allow_empty(optional)
+        .interact_text()
+        .unwrap();
+    if account_id.is_empty() {
+        return Ok(None);
+    }
+    match account_id.parse() {
+        Ok(account_id) => Ok(Some(account_id)),
+        Err(err) => {
+            println!("<{}> is not a valid account ID: {}", account_id, err);
+            input_account_id(optional)
         }
     }
 }
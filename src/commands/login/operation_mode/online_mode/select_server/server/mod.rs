@@ -5,74 +5,51 @@ use url_open::UrlOpen;
 
 /// предустановленный RPC-сервер
 #[derive(Debug, Default, Clone, clap::Clap)]
-pub struct CliServer {}
-
-/// данные для custom server
-#[derive(Debug, Default, Clone, clap::Clap)]
-pub struct CliCustomServer {
+pub struct CliServer {
+    /// type the account ID instead of receiving it from the wallet on a local listener
+    #[clap(long)]
+    manual: bool,
+    /// seconds to wait for the wallet before asking for the account ID (300 by default)
     #[clap(long)]
-    pub url: Option<url::Url>,
+    callback_timeout: Option<u64>,
 }
 
 #[derive(Debug, Clone)]
 pub struct Server {
     pub connection_config: crate::common::ConnectionConfig,
+    pub manual: bool,
+    pub callback_timeout: Option<u64>,
 }
 
-impl CliCustomServer {
+impl CliServer {
     pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
         let mut args = std::collections::VecDeque::new();
-        if let Some(url) = &self.url {
-            args.push_front(url.to_string());
-            args.push_front("--url".to_string());
+        if let Some(callback_timeout) = &self.callback_timeout {
+            args.push_front(callback_timeout.to_string());
+            args.push_front("--callback-timeout".to_owned());
+        }
+        if self.manual {
+            args.push_front("--manual".to_owned());
         }
         args
     }
 }
 
-impl From<Server> for CliCustomServer {
+impl From<Server> for CliServer {
     fn from(server: Server) -> Self {
         Self {
-            url: Some(
-                crate::common::AvailableRpcServerUrl::from_str(
-                    server.connection_config.rpc_url().as_str(),
-                )
-                .unwrap()
-                .inner,
-            ),
+            manual: server.manual,
+            callback_timeout: server.callback_timeout,
         }
     }
 }
 
-impl CliServer {
-    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
-        std::collections::VecDeque::new()
-    }
-}
-
-impl From<Server> for CliServer {
-    fn from(_: Server) -> Self {
-        Self {}
-    }
-}
-
 impl CliServer {
     pub fn into_server(self, connection_config: crate::common::ConnectionConfig) -> Server {
-        Server { connection_config }
-    }
-}
-
-impl CliCustomServer {
-    pub fn into_server(self) -> Server {
-        let url: url::Url = match self.url {
-            Some(url) => url,
-            None => Input::new()
-                .with_prompt("What is the wallet url?")
-                .interact_text()
-                .unwrap(),
-        };
         Server {
-            connection_config: crate::common::ConnectionConfig::Custom { url },
+            connection_config,
+            manual: self.manual,
+            callback_timeout: self.callback_timeout,
         }
     }
 }
@@ -80,24 +57,69 @@ impl CliCustomServer {
 impl Server {
     pub async fn process(self) -> crate::CliResult {
         let key_pair_properties: crate::common::KeyPairProperties =
-            crate::common::generate_keypair().await?;
-        let mut url: url::Url = self.connection_config.wallet_url().join("login/")?;
+            crate::common::generate_keypair(near_crypto::KeyType::ED25519, "").await?;
+        let public_key: near_crypto::PublicKey =
+            near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?;
+
+        let callback_listener = if self.manual {
+            None
+        } else {
+            match CallbackListener::start() {
+                Ok(callback_listener) => Some(callback_listener),
+                Err(err) => {
+                    eprintln!(
+                        "Failed to start a local listener for the wallet ({}), the account ID will be asked instead",
+                        err
+                    );
+                    None
+                }
+            }
+        };
+
+        let mut url: url::Url = self.connection_config.wallet_url()?.join("login/")?;
         url.query_pairs_mut()
             .append_pair("title", "NEAR CLI")
             .append_pair("public_key", &key_pair_properties.public_key_str);
-        // Use `success_url` once capture mode is implemented
-        //.append_pair("success_url", "http://127.0.0.1:8080");
+        if let Some(callback_listener) = &callback_listener {
+            url.query_pairs_mut()
+                .append_pair("success_url", callback_listener.success_url.as_str())
+                .append_pair("failure_url", callback_listener.failure_url.as_str());
+        }
         println!(
             "If your browser doesn't automatically open, please visit this URL:\n {}\n",
             &url.as_str()
         );
         url.open();
 
-        let public_key: near_crypto::PublicKey =
-            near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?;
-
-        let account_id = get_account_from_cli(public_key, self.connection_config.clone()).await?;
-        // save_account(&account_id, key_pair_properties, self.connection_config).await?
+        let account_id = match callback_listener {
+            Some(callback_listener) => {
+                let timeout = std::time::Duration::from_secs(
+                    self.callback_timeout.unwrap_or(DEFAULT_CALLBACK_TIMEOUT),
+                );
+                println!("Waiting for the wallet to redirect back to near-cli...");
+                match callback_listener.wait(timeout).await {
+                    Ok(redirect) => {
+                        let account_id = redirect.account_id(&public_key)?;
+                        verify_account_id(
+                            account_id.clone(),
+                            public_key.clone(),
+                            self.connection_config.clone(),
+                        )
+                        .await
+                        .map_err(|err| {
+                            color_eyre::Report::msg(format!("Failed account ID: {:?}", err))
+                        })?;
+                        println!("The wallet authorized the key for <{}>", account_id);
+                        account_id
+                    }
+                    Err(err) => {
+                        eprintln!("{}", err);
+                        get_account_from_cli(public_key, self.connection_config.clone()).await?
+                    }
+                }
+            }
+            None => get_account_from_cli(public_key, self.connection_config.clone()).await?,
+        };
         crate::common::save_access_key_to_keychain(
             Some(self.connection_config),
             key_pair_properties.clone(),
@@ -111,34 +133,163 @@ impl Server {
     }
 }
 
+const DEFAULT_CALLBACK_TIMEOUT: u64 = 300;
+
+/// The query the wallet appends to `success_url` after the user has added the key
+#[derive(Debug, Clone, serde::Deserialize)]
+struct WalletRedirect {
+    account_id: String,
+    public_key: Option<String>,
+}
+
+impl WalletRedirect {
+    /// The signed-in account, if the wallet has added the key that near-cli asked for.
+    fn account_id(
+        &self,
+        public_key: &near_crypto::PublicKey,
+    ) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        if let Some(redirect_public_key) = &self.public_key {
+            if redirect_public_key != &public_key.to_string() {
+                return Err(color_eyre::Report::msg(format!(
+                    "The wallet has added the key {} instead of {}",
+                    redirect_public_key, public_key
+                )));
+            }
+        }
+        near_primitives::types::AccountId::from_str(&self.account_id).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "The wallet has returned an invalid account ID <{}>: {}",
+                self.account_id, err
+            ))
+        })
+    }
+}
+
+type CallbackSender = std::sync::Mutex<std::sync::mpsc::Sender<Result<WalletRedirect, String>>>;
+
+/// A short-lived HTTP listener on localhost that receives the redirect of the wallet
+struct CallbackListener {
+    success_url: url::Url,
+    failure_url: url::Url,
+    server: actix_web::dev::Server,
+    receiver: std::sync::mpsc::Receiver<Result<WalletRedirect, String>>,
+}
+
+impl CallbackListener {
+    fn start() -> std::io::Result<Self> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let sender = actix_web::web::Data::new(std::sync::Mutex::new(sender));
+        let http_server = actix_web::HttpServer::new(move || {
+            actix_web::App::new()
+                .app_data(sender.clone())
+                .route("/success", actix_web::web::get().to(wallet_success))
+                .route("/failure", actix_web::web::get().to(wallet_failure))
+        })
+        .workers(1)
+        .bind(("127.0.0.1", 0))?;
+        let address = http_server.addrs().into_iter().next().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::Other, "no address to listen on")
+        })?;
+        let server = http_server.run();
+        actix::spawn({
+            let server = server.clone();
+            async move {
+                let _ = server.await;
+            }
+        });
+        let base_url = url::Url::parse(&format!("http://{}/", address))
+            .expect("A socket address makes a valid URL");
+        Ok(Self {
+            success_url: base_url.join("success").unwrap(),
+            failure_url: base_url.join("failure").unwrap(),
+            server,
+            receiver,
+        })
+    }
+
+    /// Waits for the wallet redirect and stops the listener.
+    async fn wait(self, timeout: std::time::Duration) -> Result<WalletRedirect, String> {
+        let deadline = std::time::Instant::now() + timeout;
+        let redirect = loop {
+            match self.receiver.try_recv() {
+                Ok(redirect) => break redirect,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    break Err("The local listener for the wallet has stopped".to_owned())
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => {
+                    if std::time::Instant::now() >= deadline {
+                        break Err(format!(
+                            "The wallet has not redirected back in {} seconds",
+                            timeout.as_secs()
+                        ));
+                    }
+                    actix::clock::sleep(std::time::Duration::from_millis(200)).await;
+                }
+            }
+        };
+        self.server.stop(false).await;
+        redirect
+    }
+}
+
+async fn wallet_success(
+    redirect: actix_web::web::Query<WalletRedirect>,
+    sender: actix_web::web::Data<CallbackSender>,
+) -> actix_web::HttpResponse {
+    let redirect = redirect.into_inner();
+    let account_id = redirect.account_id.clone();
+    let _ = sender.lock().unwrap().send(Ok(redirect));
+    actix_web::HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(format!(
+            "<p>near-cli is signed in as <b>{}</b>. You can close this window.</p>",
+            html_escape(&account_id)
+        ))
+}
+
+async fn wallet_failure(sender: actix_web::web::Data<CallbackSender>) -> actix_web::HttpResponse {
+    let _ = sender
+        .lock()
+        .unwrap()
+        .send(Err("The login was not approved in the wallet".to_owned()));
+    actix_web::HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body("<p>The login was not approved. You can close this window.</p>")
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 async fn get_account_from_cli(
     public_key: near_crypto::PublicKey,
     network_connection_config: crate::common::ConnectionConfig,
 ) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
-    let account_id = input_account_id();
+    let account_id = input_account_id()?;
     verify_account_id(account_id.clone(), public_key, network_connection_config)
         .await
         .map_err(|err| color_eyre::Report::msg(format!("Failed account ID: {:?}", err)))?;
     Ok(account_id)
 }
 
-fn input_account_id() -> near_primitives::types::AccountId {
-    Input::new()
+fn input_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+    crate::common::ensure_interactive("account-id")?;
+    Ok(Input::new()
         .with_prompt("Enter account ID")
         .interact_text()
-        .unwrap()
+        .unwrap())
 }
 
-fn rpc_client(selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-    near_jsonrpc_client::new_client(&selected_server_url)
-}
-
-async fn verify_account_id(
+pub(crate) async fn verify_account_id(
     account_id: near_primitives::types::AccountId,
     public_key: near_crypto::PublicKey,
     network_connection_config: crate::common::ConnectionConfig,
 ) -> crate::CliResult {
-    rpc_client(network_connection_config.rpc_url().as_str())
+    network_connection_config
+        .rpc_client()
         .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
             block_reference: near_primitives::types::Finality::Final.into(),
             request: near_primitives::views::QueryRequest::ViewAccessKey {
@@ -155,3 +306,64 @@ async fn verify_account_id(
         })?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn callback_listener_receives_the_wallet_redirect() {
+        let public_key: near_crypto::PublicKey =
+            "ed25519:6j4b6zUaty6fD1awqcGCCU9JYGCWYUgdJhQrzfZhqE25"
+                .parse()
+                .unwrap();
+        actix::System::new().block_on(async {
+            // A fake wallet that redirects the browser to success_url
+            let callback_listener = CallbackListener::start().unwrap();
+            let mut success_url = callback_listener.success_url.clone();
+            success_url
+                .query_pairs_mut()
+                .append_pair("account_id", "volodymyr.testnet")
+                .append_pair("public_key", &public_key.to_string())
+                .append_pair("all_keys", &public_key.to_string());
+            let response = awc::Client::default()
+                .get(success_url.as_str())
+                .send()
+                .await
+                .unwrap();
+            assert!(response.status().is_success());
+            let redirect = callback_listener
+                .wait(std::time::Duration::from_secs(5))
+                .await
+                .unwrap();
+            assert_eq!(
+                redirect.account_id(&public_key).unwrap().to_string(),
+                "volodymyr.testnet"
+            );
+
+            // A redirect for another key is rejected
+            let other_public_key: near_crypto::PublicKey =
+                "ed25519:2G9P9453x98uF4sPSs4BbFzy6iMg8DXyGVKsQwpCpnL5"
+                    .parse()
+                    .unwrap();
+            assert!(redirect.account_id(&other_public_key).is_err());
+
+            // A denied login and a wallet that never redirects fall back to the prompt
+            let callback_listener = CallbackListener::start().unwrap();
+            awc::Client::default()
+                .get(callback_listener.failure_url.as_str())
+                .send()
+                .await
+                .unwrap();
+            assert!(callback_listener
+                .wait(std::time::Duration::from_secs(5))
+                .await
+                .is_err());
+            let callback_listener = CallbackListener::start().unwrap();
+            assert!(callback_listener
+                .wait(std::time::Duration::from_millis(300))
+                .await
+                .is_err());
+        });
+    }
+}
@@ -0,0 +1,309 @@
+/// удаление ключей аккаунта из хранилища и, по желанию, из аккаунта
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliLogout {
+    /// имя сети из конфигурационного файла (например, testnet или mainnet)
+    network: Option<crate::common::ConnectionConfig>,
+    account_id: Option<near_primitives::types::AccountId>,
+    /// log out of this key only (all the keys of the account in the keychain by default)
+    #[clap(long)]
+    public_key: Option<near_crypto::PublicKey>,
+    /// also delete the keys from the account with a DeleteKey transaction
+    #[clap(long)]
+    delete_access_keys: bool,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug, Clone)]
+pub struct Logout {
+    connection_config: crate::common::ConnectionConfig,
+    account_id: near_primitives::types::AccountId,
+    public_key: Option<near_crypto::PublicKey>,
+    /// How to sign the DeleteKey transactions; `None` keeps the keys on-chain
+    sign_option:
+        Option<crate::commands::construct_transaction_command::sign_transaction::SignTransaction>,
+}
+
+impl CliLogout {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .sign_option
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        if self.delete_access_keys {
+            args.push_front("--delete-access-keys".to_owned());
+        }
+        if let Some(public_key) = &self.public_key {
+            args.push_front(public_key.to_string());
+            args.push_front("--public-key".to_owned());
+        }
+        if let Some(account_id) = &self.account_id {
+            args.push_front(account_id.to_string());
+        }
+        if let Some(network) = &self.network {
+            args.push_front(network.to_string());
+        }
+        args
+    }
+}
+
+impl From<Logout> for CliLogout {
+    fn from(logout: Logout) -> Self {
+        Self {
+            network: Some(logout.connection_config),
+            account_id: Some(logout.account_id),
+            public_key: logout.public_key,
+            delete_access_keys: logout.sign_option.is_some(),
+            sign_option: logout.sign_option.map(Into::into),
+        }
+    }
+}
+
+impl Logout {
+    pub fn from(item: CliLogout) -> color_eyre::eyre::Result<Self> {
+        let connection_config = match item.network {
+            Some(connection_config) => connection_config,
+            None => crate::common::ConnectionConfig::choose_network("Select the network")?,
+        };
+        let account_id: near_primitives::types::AccountId = match item.account_id {
+            Some(account_id) => account_id,
+            None => crate::commands::keychain_command::input_account_id(&connection_config)?,
+        };
+        let sign_option = match (item.sign_option, item.delete_access_keys) {
+            (Some(cli_sign_transaction), _) => Some(
+                crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(
+                    cli_sign_transaction,
+                    Some(connection_config.clone()),
+                    account_id.clone(),
+                )?,
+            ),
+            (None, true) => Some(
+                crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(
+                    Some(connection_config.clone()),
+                    account_id.clone(),
+                )?,
+            ),
+            (None, false) => None,
+        };
+        Ok(Self {
+            connection_config,
+            account_id,
+            public_key: item.public_key,
+            sign_option,
+        })
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        let credentials_dirs =
+            crate::keychain::credentials_search_path(Some(&self.connection_config))?;
+        let public_keys =
+            crate::keychain::find_account_keys(&credentials_dirs, &self.account_id.to_string())?
+                .into_iter()
+                .map(|key| key.public_key)
+                .filter(|public_key| match &self.public_key {
+                    Some(selected_public_key) => public_key == &selected_public_key.to_string(),
+                    None => true,
+                })
+                .collect::<Vec<_>>();
+        if public_keys.is_empty() {
+            return Err(color_eyre::Report::msg(format!(
+                "There are no keys{} of <{}> for network <{}> in the keychain",
+                self.public_key
+                    .as_ref()
+                    .map(|public_key| format!(" {}", public_key))
+                    .unwrap_or_default(),
+                self.account_id,
+                self.connection_config.network_name()
+            )));
+        }
+
+        if let Some(sign_option) = &self.sign_option {
+            let access_keys = self.access_key_list().await?;
+            check_full_access_key_is_left(&access_keys, &public_keys)?;
+            for public_key in public_keys.iter() {
+                let public_key: near_crypto::PublicKey = public_key.parse().map_err(|err| {
+                    color_eyre::Report::msg(format!("Invalid public key {}: {:?}", public_key, err))
+                })?;
+                if !access_keys
+                    .iter()
+                    .any(|access_key| access_key.public_key == public_key)
+                {
+                    println!(
+                        "The key {} is not an access key of <{}> anymore",
+                        public_key, self.account_id
+                    );
+                    continue;
+                }
+                println!(
+                    "\nDeleting the key {} from <{}>",
+                    public_key, self.account_id
+                );
+                let delete_access_key =
+                    crate::commands::delete_command::access_key::DeleteAccessKeyType {
+                        public_key,
+                        sign_option: sign_option.clone(),
+                    };
+                delete_access_key
+                    .process(
+                        near_primitives::transaction::Transaction {
+                            signer_id: self.account_id.clone(),
+                            receiver_id: self.account_id.clone(),
+                            ..prepopulated_unsigned_transaction.clone()
+                        },
+                        Some(self.connection_config.clone()),
+                    )
+                    .await?;
+            }
+        }
+
+        let mut removed_keys = vec![];
+        for public_key in public_keys {
+            // Every copy of the key is removed, so none of them is used for signing afterwards
+            let mut removed_paths = vec![];
+            for credentials_dir in credentials_dirs.iter() {
+                removed_paths.extend(crate::keychain::remove_key(
+                    credentials_dir,
+                    &self.account_id.to_string(),
+                    &public_key,
+                )?);
+            }
+            println!(
+                "\nThe key {} of <{}> has been removed:",
+                public_key, self.account_id
+            );
+            for path in removed_paths.iter() {
+                println!("  {}", path.display());
+            }
+            removed_keys.push(serde_json::json!({
+                "public_key": public_key,
+                "files": removed_paths,
+            }));
+        }
+        crate::common::add_json_output(
+            "logout",
+            serde_json::json!({
+                "account_id": self.account_id,
+                "removed_keys": removed_keys,
+                "deleted_access_keys": self.sign_option.is_some(),
+            }),
+        );
+        Ok(())
+    }
+
+    async fn access_key_list(
+        &self,
+    ) -> color_eyre::eyre::Result<Vec<near_primitives::views::AccessKeyInfoView>> {
+        let query_view_method_response = self
+            .connection_config
+            .rpc_client()
+            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccessKeyList {
+                    account_id: self.account_id.clone(),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch query for view key list: {:?}",
+                    err
+                ))
+            })?;
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKeyList(result) =
+            query_view_method_response.kind
+        {
+            Ok(result.keys)
+        } else {
+            Err(color_eyre::Report::msg("Error call result"))
+        }
+    }
+}
+
+/// Refuses to delete every full access key of the account: nothing could manage it afterwards.
+fn check_full_access_key_is_left(
+    access_keys: &[near_primitives::views::AccessKeyInfoView],
+    deleted_public_keys: &[String],
+) -> crate::CliResult {
+    let (deleted, left): (Vec<_>, Vec<_>) = access_keys
+        .iter()
+        .filter(|access_key| {
+            matches!(
+                access_key.access_key.permission,
+                near_primitives::views::AccessKeyPermissionView::FullAccess
+            )
+        })
+        .partition(|access_key| deleted_public_keys.contains(&access_key.public_key.to_string()));
+    if !deleted.is_empty() && left.is_empty() {
+        return Err(color_eyre::Report::msg(format!(
+            "Refusing to delete {}: the account would be left without a full access key. Log out without --delete-access-keys to remove the key from the keychain only",
+            deleted
+                .iter()
+                .map(|access_key| access_key.public_key.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_last_full_access_key_is_not_deleted() {
+        let access_key = |public_key: &str, permission| near_primitives::views::AccessKeyInfoView {
+            public_key: public_key.parse().unwrap(),
+            access_key: near_primitives::views::AccessKeyView {
+                nonce: 0,
+                permission,
+            },
+        };
+        let full_access_key = "ed25519:6j4b6zUaty6fD1awqcGCCU9JYGCWYUgdJhQrzfZhqE25";
+        let other_full_access_key = "ed25519:2G9P9453x98uF4sPSs4BbFzy6iMg8DXyGVKsQwpCpnL5";
+        let function_call_key = "ed25519:7FmDRADa1v4BcLiiR9MPPdmWQp3Um1iPdAYATvBY1YzS";
+        let access_keys = vec![
+            access_key(
+                full_access_key,
+                near_primitives::views::AccessKeyPermissionView::FullAccess,
+            ),
+            access_key(
+                function_call_key,
+                near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                    allowance: None,
+                    receiver_id: "contract.testnet".to_owned(),
+                    method_names: vec![],
+                },
+            ),
+        ];
+        assert!(
+            check_full_access_key_is_left(&access_keys, &[function_call_key.to_owned()]).is_ok()
+        );
+        assert!(
+            check_full_access_key_is_left(&access_keys, &[full_access_key.to_owned()]).is_err()
+        );
+
+        let mut access_keys = access_keys;
+        access_keys.push(access_key(
+            other_full_access_key,
+            near_primitives::views::AccessKeyPermissionView::FullAccess,
+        ));
+        assert!(check_full_access_key_is_left(&access_keys, &[full_access_key.to_owned()]).is_ok());
+        assert!(check_full_access_key_is_left(
+            &access_keys,
+            &[full_access_key.to_owned(), other_full_access_key.to_owned()]
+        )
+        .is_err());
+    }
+}
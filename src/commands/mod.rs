@@ -6,7 +6,9 @@ pub mod construct_transaction_command;
 pub mod delete_command;
 pub mod execute_command;
 pub mod generate_shell_completions_command;
+pub mod keychain_command;
 pub mod login;
+pub mod logout;
 pub mod transfer_command;
 pub mod utils_command;
 pub mod view_command;
@@ -23,8 +25,12 @@ pub enum CliTopLevelCommand {
     Execute(self::execute_command::CliOptionMethod),
     /// Use these to generate static shell completions
     GenerateShellCompletions(self::generate_shell_completions_command::CliGenerateShellCompletions),
+    /// Use these to list, show, import, export and remove the keys saved in ~/.near-credentials
+    Keychain(self::keychain_command::CliKeychain),
     /// Use these to login with wallet authorization
     Login(self::login::operation_mode::CliOperationMode),
+    /// Use these to remove the keys of an account from the keychain and, optionally, from the account
+    Logout(self::logout::CliLogout),
     /// Use these to transfer tokens
     Transfer(self::transfer_command::CliCurrency),
     /// Helpers
@@ -38,6 +44,8 @@ pub enum CliTopLevelCommand {
 pub enum TopLevelCommand {
     #[strum_discriminants(strum(message = "Login with wallet authorization"))]
     Login(self::login::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Logout and remove the saved keys of an account"))]
+    Logout(self::logout::Logout),
     #[strum_discriminants(strum(
         message = "View account, contract code, contract state, transaction, nonce, recent block hash"
     ))]
@@ -54,6 +62,8 @@ pub enum TopLevelCommand {
     Delete(self::delete_command::DeleteAction),
     #[strum_discriminants(strum(message = "Construct a new transaction"))]
     ConstructTransaction(self::construct_transaction_command::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Manage the saved keys"))]
+    Keychain(self::keychain_command::Keychain),
     #[strum_discriminants(strum(message = "Helpers"))]
     Utils(self::utils_command::Utils),
 }
@@ -66,6 +76,11 @@ impl CliTopLevelCommand {
                 args.push_front("login".to_owned());
                 args
             }
+            Self::Logout(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("logout".to_owned());
+                args
+            }
             Self::Execute(subcommand) => {
                 let mut args = subcommand.to_cli_args();
                 args.push_front("execute".to_owned());
@@ -96,6 +111,11 @@ impl CliTopLevelCommand {
                 args.push_front("construct-transaction".to_owned());
                 args
             }
+            Self::Keychain(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("keychain".to_owned());
+                args
+            }
             Self::Utils(subcommand) => {
                 let mut args = subcommand.to_cli_args();
                 args.push_front("utils".to_owned());
@@ -110,6 +130,7 @@ impl From<TopLevelCommand> for CliTopLevelCommand {
     fn from(top_level_command: TopLevelCommand) -> Self {
         match top_level_command {
             TopLevelCommand::Login(operation_mode) => Self::Login(operation_mode.into()),
+            TopLevelCommand::Logout(logout) => Self::Logout(logout.into()),
             TopLevelCommand::Execute(option_method) => Self::Execute(option_method.into()),
             TopLevelCommand::Add(add_action) => Self::Add(add_action.into()),
             TopLevelCommand::Delete(delete_action) => Self::Delete(delete_action.into()),
@@ -118,56 +139,65 @@ impl From<TopLevelCommand> for CliTopLevelCommand {
             TopLevelCommand::ConstructTransaction(operation_mode) => {
                 Self::ConstructTransaction(operation_mode.into())
             }
+            TopLevelCommand::Keychain(keychain) => Self::Keychain(keychain.into()),
             TopLevelCommand::Utils(utils) => Self::Utils(utils.into()),
         }
     }
 }
 
-impl From<CliTopLevelCommand> for TopLevelCommand {
-    fn from(cli_top_level_command: CliTopLevelCommand) -> Self {
+impl TopLevelCommand {
+    pub fn from(cli_top_level_command: CliTopLevelCommand) -> color_eyre::eyre::Result<Self> {
         match cli_top_level_command {
-            CliTopLevelCommand::Add(cli_add_action) => {
-                TopLevelCommand::Add(self::add_command::AddAction::from(cli_add_action).unwrap())
-            }
+            CliTopLevelCommand::Add(cli_add_action) => Ok(TopLevelCommand::Add(
+                self::add_command::AddAction::from(cli_add_action)?,
+            )),
             CliTopLevelCommand::ConstructTransaction(cli_operation_mode) => {
-                TopLevelCommand::ConstructTransaction(
+                Ok(TopLevelCommand::ConstructTransaction(
                     self::construct_transaction_command::operation_mode::OperationMode::from(
                         cli_operation_mode,
-                    )
-                    .unwrap(),
-                )
-            }
-            CliTopLevelCommand::Delete(cli_delete_action) => TopLevelCommand::Delete(
-                self::delete_command::DeleteAction::from(cli_delete_action).unwrap(),
-            ),
-            CliTopLevelCommand::Execute(cli_option_method) => TopLevelCommand::Execute(
-                self::execute_command::OptionMethod::from(cli_option_method).unwrap(),
-            ),
+                    )?,
+                ))
+            }
+            CliTopLevelCommand::Delete(cli_delete_action) => Ok(TopLevelCommand::Delete(
+                self::delete_command::DeleteAction::from(cli_delete_action)?,
+            )),
+            CliTopLevelCommand::Execute(cli_option_method) => Ok(TopLevelCommand::Execute(
+                self::execute_command::OptionMethod::from(cli_option_method)?,
+            )),
             CliTopLevelCommand::GenerateShellCompletions(_) => {
                 unreachable!("This variant is handled in the main function")
             }
-            CliTopLevelCommand::Login(cli_option_method) => {
-                TopLevelCommand::Login(cli_option_method.into())
-            }
-            CliTopLevelCommand::Transfer(cli_currency) => TopLevelCommand::Transfer(
-                self::transfer_command::Currency::from(cli_currency).unwrap(),
-            ),
-            CliTopLevelCommand::Utils(cli_util) => TopLevelCommand::Utils(cli_util.into()),
-            CliTopLevelCommand::View(cli_view_query_request) => {
-                TopLevelCommand::View(cli_view_query_request.into())
-            }
+            CliTopLevelCommand::Keychain(cli_keychain) => Ok(TopLevelCommand::Keychain(
+                self::keychain_command::Keychain::from(cli_keychain)?,
+            )),
+            CliTopLevelCommand::Login(cli_option_method) => Ok(TopLevelCommand::Login(
+                self::login::operation_mode::OperationMode::from(cli_option_method)?,
+            )),
+            CliTopLevelCommand::Logout(cli_logout) => Ok(TopLevelCommand::Logout(
+                self::logout::Logout::from(cli_logout)?,
+            )),
+            CliTopLevelCommand::Transfer(cli_currency) => Ok(TopLevelCommand::Transfer(
+                self::transfer_command::Currency::from(cli_currency)?,
+            )),
+            CliTopLevelCommand::Utils(cli_util) => Ok(TopLevelCommand::Utils(
+                self::utils_command::Utils::from(cli_util)?,
+            )),
+            CliTopLevelCommand::View(cli_view_query_request) => Ok(TopLevelCommand::View(
+                self::view_command::ViewQueryRequest::from(cli_view_query_request)?,
+            )),
         }
     }
 }
 
 impl TopLevelCommand {
-    pub fn choose_command() -> Self {
+    pub fn choose_command() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = TopLevelCommandDiscriminants::iter().collect::<Vec<_>>();
         let commands = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("command")?;
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Choose your action")
             .items(&commands)
@@ -183,7 +213,11 @@ impl TopLevelCommand {
             TopLevelCommandDiscriminants::Execute => {
                 CliTopLevelCommand::Execute(Default::default())
             }
+            TopLevelCommandDiscriminants::Keychain => {
+                CliTopLevelCommand::Keychain(Default::default())
+            }
             TopLevelCommandDiscriminants::Login => CliTopLevelCommand::Login(Default::default()),
+            TopLevelCommandDiscriminants::Logout => CliTopLevelCommand::Logout(Default::default()),
             TopLevelCommandDiscriminants::Transfer => {
                 CliTopLevelCommand::Transfer(Default::default())
             }
@@ -207,7 +241,9 @@ impl TopLevelCommand {
             Self::ConstructTransaction(mode) => mode.process(unsigned_transaction).await,
             Self::Delete(delete_action) => delete_action.process(unsigned_transaction).await,
             Self::Execute(option_method) => option_method.process(unsigned_transaction).await,
+            Self::Keychain(keychain) => keychain.process().await,
             Self::Login(mode) => mode.process().await,
+            Self::Logout(logout) => logout.process(unsigned_transaction).await,
             Self::Transfer(currency) => currency.process(unsigned_transaction).await,
             Self::Utils(util_type) => util_type.process().await,
             Self::View(view_query_request) => view_query_request.process().await,
@@ -114,6 +114,7 @@ impl CurrencySelection {
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("currency")?;
         let selected_currency = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("What do you want to transfer?")
             .items(&currencies)
@@ -123,7 +124,7 @@ impl CurrencySelection {
         let cli_currency = match variants[selected_currency] {
             CurrencySelectionDiscriminants::NEAR => CliCurrencySelection::NEAR(Default::default()),
         };
-        Ok(Self::from(cli_currency)?)
+        Self::from(cli_currency)
     }
 
     async fn process(
@@ -54,38 +54,41 @@ impl From<CombineTransactionSignature> for CliCombineTransactionSignature {
     }
 }
 
-impl From<CliCombineTransactionSignature> for CombineTransactionSignature {
-    fn from(item: CliCombineTransactionSignature) -> Self {
+impl CombineTransactionSignature {
+    pub fn from(item: CliCombineTransactionSignature) -> color_eyre::eyre::Result<Self> {
         let signature: near_crypto::Signature = match item.signature {
             Some(cli_signature) => cli_signature,
-            None => CombineTransactionSignature::input_signature(),
+            None => CombineTransactionSignature::input_signature()?,
         };
         let unsigned_transaction: near_primitives::transaction::Transaction =
             match item.unsigned_transaction {
                 Some(cli_unsigned_transaction) => cli_unsigned_transaction.inner,
-                None => CombineTransactionSignature::input_unsigned_transaction(),
+                None => CombineTransactionSignature::input_unsigned_transaction()?,
             };
-        Self {
+        Ok(Self {
             signature,
             unsigned_transaction,
-        }
+        })
     }
 }
 
 impl CombineTransactionSignature {
-    pub fn input_signature() -> near_crypto::Signature {
-        Input::new()
+    pub fn input_signature() -> color_eyre::eyre::Result<near_crypto::Signature> {
+        crate::common::ensure_interactive("signature")?;
+        Ok(Input::new()
             .with_prompt("Enter the signature")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
-    pub fn input_unsigned_transaction() -> near_primitives::transaction::Transaction {
+    pub fn input_unsigned_transaction(
+    ) -> color_eyre::eyre::Result<near_primitives::transaction::Transaction> {
+        crate::common::ensure_interactive("unsigned-transaction")?;
         let input: crate::common::TransactionAsBase64 = Input::new()
             .with_prompt("Enter an unsigned transaction")
             .interact_text()
             .unwrap();
-        input.inner
+        Ok(input.inner)
     }
 
     pub async fn process(self) -> crate::CliResult {
@@ -102,6 +105,14 @@ impl CombineTransactionSignature {
         println!("Signed transaction:");
         crate::common::print_transaction(signed_transaction.transaction.clone());
         println!("{:<13} {}", "signature:", signed_transaction.signature);
+        crate::common::add_json_output(
+            "signature",
+            serde_json::json!(signed_transaction.signature),
+        );
+        crate::common::add_json_output(
+            "signed_transaction_base64",
+            serde_json::json!(serialize_to_base64),
+        );
         println!(
             "Base64-encoded signed transaction:\n{}",
             serialize_to_base64
@@ -0,0 +1,199 @@
+use std::str::FromStr;
+
+/// Derive the keys m/44'/397'/i' of a range of indexes from a seed phrase
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliDeriveKeys {
+    /// the seed phrase itself, @path/to/file, - (stdin) or env:VAR_NAME
+    #[clap(long)]
+    seed_phrase: Option<crate::common::SecretArg<String>>,
+    #[clap(flatten)]
+    seed_phrase_passphrase: crate::common::CliSeedPhrasePassphrase,
+    /// the first index i of m/44'/397'/i'
+    #[clap(long)]
+    from_index: Option<u32>,
+    /// the number of keys to derive
+    #[clap(long)]
+    count: Option<u32>,
+    /// ed25519 (by default) or secp256k1
+    #[clap(long)]
+    key_type: Option<near_crypto::KeyType>,
+    /// show the private keys as well
+    #[clap(long)]
+    show_secret: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeriveKeys {
+    seed_phrase: crate::common::SecretArg<String>,
+    seed_phrase_passphrase: crate::common::CliSeedPhrasePassphrase,
+    from_index: u32,
+    count: u32,
+    key_type: near_crypto::KeyType,
+    show_secret: bool,
+}
+
+impl CliDeriveKeys {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self.seed_phrase_passphrase.to_cli_args();
+        if self.show_secret {
+            args.push_front("--show-secret".to_owned());
+        }
+        if let Some(key_type) = &self.key_type {
+            args.push_front(key_type.to_string());
+            args.push_front("--key-type".to_owned());
+        }
+        if let Some(count) = &self.count {
+            args.push_front(count.to_string());
+            args.push_front("--count".to_owned());
+        }
+        if let Some(from_index) = &self.from_index {
+            args.push_front(from_index.to_string());
+            args.push_front("--from-index".to_owned());
+        }
+        if let Some(seed_phrase) = &self.seed_phrase {
+            args.push_front(seed_phrase.to_string());
+            args.push_front("--seed-phrase".to_owned());
+        }
+        args
+    }
+}
+
+impl From<DeriveKeys> for CliDeriveKeys {
+    fn from(derive_keys: DeriveKeys) -> Self {
+        Self {
+            seed_phrase: Some(derive_keys.seed_phrase),
+            seed_phrase_passphrase: derive_keys.seed_phrase_passphrase,
+            from_index: Some(derive_keys.from_index),
+            count: Some(derive_keys.count),
+            key_type: Some(derive_keys.key_type),
+            show_secret: derive_keys.show_secret,
+        }
+    }
+}
+
+impl DeriveKeys {
+    pub fn from(item: CliDeriveKeys) -> color_eyre::eyre::Result<Self> {
+        let seed_phrase = match item.seed_phrase {
+            Some(seed_phrase) => seed_phrase,
+            None => DeriveKeys::input_seed_phrase()?,
+        };
+        let from_index = match item.from_index {
+            Some(from_index) => from_index,
+            None => DeriveKeys::input_from_index()?,
+        };
+        let count = match item.count {
+            Some(count) => count,
+            None => DeriveKeys::input_count()?,
+        };
+        Ok(Self {
+            seed_phrase,
+            seed_phrase_passphrase: item.seed_phrase_passphrase,
+            from_index,
+            count,
+            key_type: item.key_type.unwrap_or(near_crypto::KeyType::ED25519),
+            show_secret: item.show_secret,
+        })
+    }
+}
+
+impl DeriveKeys {
+    fn input_seed_phrase() -> color_eyre::eyre::Result<crate::common::SecretArg<String>> {
+        crate::common::ensure_interactive("seed-phrase")?;
+        Ok(crate::common::input_secret::<String>("Enter the seed phrase").into())
+    }
+
+    fn input_from_index() -> color_eyre::eyre::Result<u32> {
+        crate::common::ensure_interactive("from-index")?;
+        Ok(dialoguer::Input::new()
+            .with_prompt("Enter the first index i of m/44'/397'/i'")
+            .with_initial_text("0")
+            .interact_text()
+            .unwrap())
+    }
+
+    fn input_count() -> color_eyre::eyre::Result<u32> {
+        crate::common::ensure_interactive("count")?;
+        Ok(dialoguer::Input::new()
+            .with_prompt("How many keys to derive?")
+            .with_initial_text("10")
+            .interact_text()
+            .unwrap())
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let first_seed_phrase_hd_path =
+            slip10::BIP32Path::from_str(&format!("m/44'/397'/{}'", self.from_index)).map_err(
+                |err| {
+                    color_eyre::Report::msg(format!("Invalid index {}: {:?}", self.from_index, err))
+                },
+            )?;
+        let key_pairs = crate::common::get_key_pairs_properties_from_seed_phrase(
+            self.seed_phrase.inner,
+            crate::common::consecutive_seed_phrase_hd_paths(
+                &first_seed_phrase_hd_path,
+                self.count,
+            )?,
+            self.key_type,
+            &self.seed_phrase_passphrase.passphrase(false)?,
+        )?;
+        print_key_pairs(&key_pairs, self.show_secret);
+        crate::common::add_json_output("keys", key_pairs_to_json(&key_pairs, self.show_secret));
+        Ok(())
+    }
+}
+
+/// The derived keys as a JSON array, with the private keys only when asked.
+pub(super) fn key_pairs_to_json(
+    key_pairs: &[crate::common::KeyPairProperties],
+    show_secret: bool,
+) -> serde_json::Value {
+    serde_json::Value::Array(
+        key_pairs
+            .iter()
+            .map(|key_pair| {
+                let mut key = serde_json::json!({
+                    "seed_phrase_hd_path": key_pair.seed_phrase_hd_path.to_string(),
+                    "account_id": key_pair.implicit_account_id,
+                    "public_key": key_pair.public_key_str,
+                });
+                if show_secret {
+                    key["private_key"] = serde_json::json!(key_pair.secret_keypair_str);
+                }
+                key
+            })
+            .collect(),
+    )
+}
+
+/// Prints the derived keys as a table, one key per line.
+pub(super) fn print_key_pairs(key_pairs: &[crate::common::KeyPairProperties], show_secret: bool) {
+    println!(
+        "\n{:<16} {:<64} {:<52}{}",
+        "HD path",
+        "Implicit account ID",
+        "Public key",
+        if show_secret { " Private key" } else { "" }
+    );
+    for key_pair in key_pairs {
+        println!(
+            "{:<16} {:<64} {:<52}{}",
+            key_pair.seed_phrase_hd_path.to_string(),
+            key_pair
+                .implicit_account_id
+                .as_ref()
+                .map(|account_id| account_id.to_string())
+                .unwrap_or_else(|| "-".to_owned()),
+            key_pair.public_key_str,
+            if show_secret {
+                format!(" {}", key_pair.secret_keypair_str)
+            } else {
+                String::new()
+            }
+        );
+    }
+}
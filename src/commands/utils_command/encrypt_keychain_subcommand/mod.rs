@@ -0,0 +1,67 @@
+/// Encrypt the plaintext key files of the keychain with a passphrase
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliEncryptKeychain {}
+
+impl CliEncryptKeychain {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::new()
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
+        let mut credentials_dirs = std::collections::BTreeSet::new();
+        credentials_dirs.insert(home_dir.join(crate::consts::DIR_NAME_KEY_CHAIN));
+        let config = crate::config::Config::load()?;
+        for network_config in config.networks.iter() {
+            credentials_dirs.insert(home_dir.join(&network_config.credentials_dir));
+        }
+        let mut encrypted_paths = vec![];
+        let mut removed_paths = vec![];
+        for credentials_dir in credentials_dirs {
+            let encrypted_key_files =
+                crate::keychain::encrypt_existing_key_files(&credentials_dir)?;
+            encrypted_paths.extend(encrypted_key_files.encrypted_paths);
+            removed_paths.extend(encrypted_key_files.removed_paths);
+        }
+        if encrypted_paths.is_empty() && removed_paths.is_empty() {
+            println!("\nThere are no plaintext key files to encrypt");
+        }
+        if !encrypted_paths.is_empty() {
+            println!("\nEncrypted key files:");
+            for path in encrypted_paths.iter() {
+                println!("  {}", path.display());
+            }
+        }
+        if !removed_paths.is_empty() {
+            println!("\nRemoved plaintext key files of near-api-js, the JavaScript tools cannot use these keys anymore:");
+            for path in removed_paths.iter() {
+                println!("  {}", path.display());
+            }
+        }
+        if !config.keychain.encrypt {
+            println!(
+                "New key files are still saved as plaintext; set `encrypt = true` in the [keychain] section of the config file to encrypt them as well"
+            );
+        }
+        crate::common::add_json_output(
+            "encrypted_key_files",
+            serde_json::json!(encrypted_paths
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()),
+        );
+        crate::common::add_json_output(
+            "removed_key_files",
+            serde_json::json!(removed_paths
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()),
+        );
+        Ok(())
+    }
+}
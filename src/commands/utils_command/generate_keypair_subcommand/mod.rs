@@ -1,15 +1,24 @@
 use std::str::FromStr;
 
 /// Generate a key pair of private and public keys (use it anywhere you need
-/// Ed25519 keys)
+/// Ed25519 or secp256k1 keys)
 #[derive(Debug, Clone, clap::Clap)]
 pub struct CliGenerateKeypair {
+    /// the seed phrase itself, @path/to/file, - (stdin) or env:VAR_NAME
     #[clap(long)]
-    pub master_seed_phrase: Option<String>,
+    pub master_seed_phrase: Option<crate::common::SecretArg<String>>,
     #[clap(long, default_value = "12")]
     pub new_master_seed_phrase_words_count: usize,
     #[clap(long, default_value = "m/44'/397'/0'")]
     pub seed_phrase_hd_path: slip10::BIP32Path,
+    /// ed25519 or secp256k1
+    #[clap(long, default_value = "ed25519")]
+    pub key_type: near_crypto::KeyType,
+    #[clap(flatten)]
+    pub seed_phrase_passphrase: crate::common::CliSeedPhrasePassphrase,
+    /// the number of keys to derive, walking the last index of the HD path
+    #[clap(long, default_value = "1")]
+    pub count: u32,
     #[clap(long, default_value = "plaintext")]
     pub format: crate::common::OutputFormat,
 }
@@ -20,6 +29,9 @@ impl Default for CliGenerateKeypair {
             master_seed_phrase: None,
             new_master_seed_phrase_words_count: 12,
             seed_phrase_hd_path: slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap(),
+            key_type: near_crypto::KeyType::ED25519,
+            seed_phrase_passphrase: Default::default(),
+            count: 1,
             format: crate::common::OutputFormat::Json,
         }
     }
@@ -27,14 +39,46 @@ impl Default for CliGenerateKeypair {
 
 impl CliGenerateKeypair {
     pub async fn process(self) -> crate::CliResult {
-        let key_pair_properties = crate::common::generate_keypair().await?;
+        let master_seed_phrase = match &self.master_seed_phrase {
+            Some(master_seed_phrase) => master_seed_phrase.inner.clone(),
+            None => bip39::Mnemonic::generate(self.new_master_seed_phrase_words_count)?
+                .word_iter()
+                .collect::<Vec<&str>>()
+                .join(" "),
+        };
+        let seed_phrase_passphrase = self
+            .seed_phrase_passphrase
+            .passphrase(self.master_seed_phrase.is_none())?;
+        if self.count > 1 {
+            return self.process_many(master_seed_phrase, &seed_phrase_passphrase);
+        }
+        let key_pair_properties = crate::common::get_key_pair_properties_from_seed_phrase(
+            master_seed_phrase,
+            self.seed_phrase_hd_path.clone(),
+            self.key_type,
+            &seed_phrase_passphrase,
+        )?;
+        crate::common::add_json_output(
+            "key_pair",
+            serde_json::json!({
+                "master_seed_phrase": key_pair_properties.master_seed_phrase,
+                "seed_phrase_hd_path": key_pair_properties.seed_phrase_hd_path.to_string(),
+                "account_id": key_pair_properties.implicit_account_id,
+                "public_key": key_pair_properties.public_key_str,
+                "private_key": key_pair_properties.secret_keypair_str,
+            }),
+        );
         match self.format {
             crate::common::OutputFormat::Plaintext => {
                 println!(
                     "Master Seed Phrase: {}\nSeed Phrase HD Path: {}\nImplicit Account ID: {}\nPublic Key: {}\nSECRET KEYPAIR: {}",
                     key_pair_properties.master_seed_phrase,
                     key_pair_properties.seed_phrase_hd_path.to_string(),
-                    key_pair_properties.implicit_account_id,
+                    key_pair_properties
+                        .implicit_account_id
+                        .as_ref()
+                        .map(|account_id| account_id.to_string())
+                        .unwrap_or_else(|| "-".to_owned()),
                     key_pair_properties.public_key_str,
                     key_pair_properties.secret_keypair_str,
                 );
@@ -55,4 +99,42 @@ impl CliGenerateKeypair {
         };
         Ok(())
     }
+
+    fn process_many(
+        self,
+        master_seed_phrase: String,
+        seed_phrase_passphrase: &str,
+    ) -> crate::CliResult {
+        let key_pairs = crate::common::get_key_pairs_properties_from_seed_phrase(
+            master_seed_phrase.clone(),
+            crate::common::consecutive_seed_phrase_hd_paths(&self.seed_phrase_hd_path, self.count)?,
+            self.key_type,
+            seed_phrase_passphrase,
+        )?;
+        let keys = super::derive_keys_subcommand::key_pairs_to_json(&key_pairs, true);
+        crate::common::add_json_output(
+            "key_pairs",
+            serde_json::json!({
+                "master_seed_phrase": master_seed_phrase,
+                "keys": keys,
+            }),
+        );
+        match self.format {
+            crate::common::OutputFormat::Plaintext => {
+                println!("Master Seed Phrase: {}", master_seed_phrase);
+                super::derive_keys_subcommand::print_key_pairs(&key_pairs, true);
+            }
+            crate::common::OutputFormat::Json => {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "master_seed_phrase": master_seed_phrase,
+                        "keys": keys,
+                    }))
+                    .unwrap()
+                );
+            }
+        };
+        Ok(())
+    }
 }
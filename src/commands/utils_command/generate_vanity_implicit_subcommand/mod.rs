@@ -0,0 +1,304 @@
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+/// Generate a key pair whose implicit account ID starts and/or ends with the given hex digits
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliGenerateVanityImplicit {
+    /// the network to save the key for (the legacy ~/.near-credentials/default/ without it)
+    network: Option<crate::common::ConnectionConfig>,
+    /// hex digits the implicit account ID starts with
+    #[clap(long)]
+    prefix: Option<HexPattern>,
+    /// hex digits the implicit account ID ends with
+    #[clap(long)]
+    suffix: Option<HexPattern>,
+    /// the number of threads searching (all the CPU cores by default)
+    #[clap(long)]
+    threads: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GenerateVanityImplicit {
+    connection_config: Option<crate::common::ConnectionConfig>,
+    prefix: HexPattern,
+    suffix: HexPattern,
+    threads: usize,
+}
+
+/// Lowercase hex digits of an implicit account ID
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct HexPattern(String);
+
+impl std::fmt::Display for HexPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for HexPattern {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pattern = s.trim().to_lowercase();
+        if pattern.len() > 64 {
+            return Err("An implicit account ID has only 64 hex digits".to_owned());
+        }
+        if !pattern.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!(
+                "<{}> is not hex: implicit account IDs consist of 0-9 and a-f",
+                s
+            ));
+        }
+        Ok(Self(pattern))
+    }
+}
+
+impl CliGenerateVanityImplicit {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(threads) = &self.threads {
+            args.push_front(threads.to_string());
+            args.push_front("--threads".to_owned());
+        }
+        if let Some(suffix) = &self.suffix {
+            args.push_front(suffix.to_string());
+            args.push_front("--suffix".to_owned());
+        }
+        if let Some(prefix) = &self.prefix {
+            args.push_front(prefix.to_string());
+            args.push_front("--prefix".to_owned());
+        }
+        if let Some(network) = &self.network {
+            args.push_front(network.to_string());
+        }
+        args
+    }
+}
+
+impl From<GenerateVanityImplicit> for CliGenerateVanityImplicit {
+    fn from(generate_vanity_implicit: GenerateVanityImplicit) -> Self {
+        Self {
+            network: generate_vanity_implicit.connection_config,
+            prefix: Some(generate_vanity_implicit.prefix),
+            suffix: Some(generate_vanity_implicit.suffix),
+            threads: Some(generate_vanity_implicit.threads),
+        }
+    }
+}
+
+impl GenerateVanityImplicit {
+    pub fn from(item: CliGenerateVanityImplicit) -> color_eyre::eyre::Result<Self> {
+        let (prefix, suffix) = match (item.prefix, item.suffix) {
+            (None, None) => (
+                GenerateVanityImplicit::input_pattern(
+                    "Enter the prefix of the implicit account ID (leave blank for none)",
+                )?,
+                GenerateVanityImplicit::input_pattern(
+                    "Enter the suffix of the implicit account ID (leave blank for none)",
+                )?,
+            ),
+            (prefix, suffix) => (prefix.unwrap_or_default(), suffix.unwrap_or_default()),
+        };
+        Ok(Self {
+            connection_config: item
+                .network
+                .or_else(crate::common::ConnectionConfig::preselected),
+            prefix,
+            suffix,
+            threads: item.threads.unwrap_or_else(num_cpus::get).max(1),
+        })
+    }
+}
+
+impl GenerateVanityImplicit {
+    fn input_pattern(prompt: &str) -> color_eyre::eyre::Result<HexPattern> {
+        crate::common::ensure_interactive("prefix")?;
+        Ok(dialoguer::Input::new()
+            .with_prompt(prompt)
+            .allow_empty(true)
+            .interact_text()
+            .unwrap())
+    }
+
+    fn matches(&self, implicit_account_id: &str) -> bool {
+        implicit_account_id.starts_with(&self.prefix.0)
+            && implicit_account_id.ends_with(&self.suffix.0)
+    }
+
+    /// Every hex digit of the pattern is matched by one key in 16 on average.
+    fn expected_attempts(&self) -> f64 {
+        16f64.powi((self.prefix.0.len() + self.suffix.0.len()) as i32)
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        if self.prefix.0.len() + self.suffix.0.len() > 64 {
+            return Err(color_eyre::Report::msg(
+                "The prefix and the suffix together are longer than an implicit account ID",
+            ));
+        }
+        // The keys are derived from one new seed phrase, so the found key can be recovered with
+        // the seed phrase and its HD path like any other generated key
+        let master_seed_phrase = bip39::Mnemonic::generate(12)?
+            .word_iter()
+            .collect::<Vec<&str>>()
+            .join(" ");
+        let master_seed = bip39::Mnemonic::parse(&master_seed_phrase)?.to_seed("");
+        let expected_attempts = self.expected_attempts();
+        println!(
+            "Searching for an implicit account ID matching {}...{} on {} threads, {:.0} keys to check on average",
+            self.prefix, self.suffix, self.threads, expected_attempts
+        );
+
+        let found = std::sync::Arc::new(AtomicBool::new(false));
+        let attempts = std::sync::Arc::new(AtomicU64::new(0));
+        let started = std::time::Instant::now();
+        let (sender, receiver) = std::sync::mpsc::channel::<u32>();
+        let threads = (0..self.threads)
+            .map(|thread_index| {
+                let sender = sender.clone();
+                let (found, attempts) = (found.clone(), attempts.clone());
+                let search = self.clone();
+                std::thread::spawn(move || {
+                    // Each thread walks its own HD indexes: thread_index, thread_index + threads, ...
+                    let mut index = thread_index as u32;
+                    while !found.load(Ordering::Relaxed) && index < 1 << 31 {
+                        let seed_phrase_hd_path =
+                            slip10::BIP32Path::from_str(&format!("m/44'/397'/{}'", index)).unwrap();
+                        let derived_private_key = slip10::derive_key_from_path(
+                            &master_seed,
+                            slip10::Curve::Ed25519,
+                            &seed_phrase_hd_path,
+                        )
+                        .unwrap();
+                        let secret =
+                            ed25519_dalek::SecretKey::from_bytes(&derived_private_key.key).unwrap();
+                        let public = ed25519_dalek::PublicKey::from(&secret);
+                        attempts.fetch_add(1, Ordering::Relaxed);
+                        if search.matches(&hex::encode(public)) {
+                            found.store(true, Ordering::Relaxed);
+                            let _ = sender.send(index);
+                            return;
+                        }
+                        index = match index.checked_add(search.threads as u32) {
+                            Some(index) => index,
+                            None => return,
+                        };
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        drop(sender);
+        let found_index = loop {
+            match receiver.recv_timeout(std::time::Duration::from_secs(2)) {
+                Ok(index) => break Some(index),
+                Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => break None,
+                Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
+                    let checked = attempts.load(Ordering::Relaxed);
+                    let keys_per_second = checked as f64 / started.elapsed().as_secs_f64();
+                    eprintln!(
+                        "Checked {} keys, {:.0} keys/s, {} expected in total",
+                        checked,
+                        keys_per_second,
+                        format_duration(expected_attempts / keys_per_second)
+                    );
+                }
+            }
+        };
+        found.store(true, Ordering::Relaxed);
+        for thread in threads {
+            thread
+                .join()
+                .map_err(|_| color_eyre::Report::msg("A search thread panicked"))?;
+        }
+
+        let elapsed = started.elapsed().as_secs_f64();
+        let checked = attempts.load(Ordering::Relaxed);
+        let index = found_index.ok_or_else(|| {
+            color_eyre::Report::msg(format!(
+                "No matching key among the {} keys of the seed phrase",
+                checked
+            ))
+        })?;
+        let key_pair_properties = crate::common::get_key_pair_properties_from_seed_phrase(
+            master_seed_phrase,
+            slip10::BIP32Path::from_str(&format!("m/44'/397'/{}'", index)).unwrap(),
+            near_crypto::KeyType::ED25519,
+            "",
+        )?;
+        let implicit_account_id = key_pair_properties
+            .implicit_account_id
+            .as_ref()
+            .expect("ed25519 keys have implicit accounts")
+            .to_string();
+        println!(
+            "Found {} after checking {} keys in {} ({:.0} keys/s)",
+            implicit_account_id,
+            checked,
+            format_duration(elapsed),
+            checked as f64 / elapsed
+        );
+        crate::common::add_json_output(
+            "vanity_implicit",
+            serde_json::json!({
+                "account_id": implicit_account_id,
+                "public_key": key_pair_properties.public_key_str,
+                "seed_phrase_hd_path": key_pair_properties.seed_phrase_hd_path.to_string(),
+                "checked_keys": checked,
+                "seconds": elapsed,
+            }),
+        );
+        crate::common::save_access_key_to_keychain(
+            self.connection_config,
+            key_pair_properties,
+            &implicit_account_id,
+        )
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to save a file with access key: {}", err))
+        })
+    }
+}
+
+fn format_duration(seconds: f64) -> String {
+    if !seconds.is_finite() {
+        "an unknown time".to_owned()
+    } else if seconds < 60.0 {
+        format!("{:.1}s", seconds)
+    } else if seconds < 3600.0 {
+        format!("{:.1}min", seconds / 60.0)
+    } else if seconds < 86400.0 {
+        format!("{:.1}h", seconds / 3600.0)
+    } else {
+        format!("{:.1} days", seconds / 86400.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vanity_pattern_matching() {
+        assert_eq!(
+            HexPattern::from_str("ABc").unwrap(),
+            HexPattern("abc".to_owned())
+        );
+        assert!(HexPattern::from_str("xyz").is_err());
+        assert!(HexPattern::from_str(&"a".repeat(65)).is_err());
+
+        let search = GenerateVanityImplicit {
+            connection_config: None,
+            prefix: HexPattern("ab".to_owned()),
+            suffix: HexPattern("9".to_owned()),
+            threads: 1,
+        };
+        assert!(search.matches("ab0123456789"));
+        assert!(!search.matches("ba0123456789"));
+        assert!(!search.matches("ab0123456780"));
+        assert_eq!(search.expected_attempts(), 4096.0);
+    }
+}
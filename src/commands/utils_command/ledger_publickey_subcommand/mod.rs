@@ -44,6 +44,16 @@ impl CliLedgerPublicKey {
             })?;
 
         let implicit_account_id = hex::encode(&public_key);
+        crate::common::add_json_output(
+            "key",
+            serde_json::json!({
+                "seed_phrase_hd_path": self.seed_phrase_hd_path.to_string(),
+                "account_id": implicit_account_id,
+                "public_key": near_crypto::PublicKey::ED25519(near_crypto::ED25519PublicKey::from(
+                    public_key.to_bytes(),
+                )),
+            }),
+        );
 
         match self.format {
             crate::common::OutputFormat::Plaintext => {
@@ -2,7 +2,10 @@ use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 mod combine_transaction_subcommand_with_signature;
+mod derive_keys_subcommand;
+mod encrypt_keychain_subcommand;
 pub mod generate_keypair_subcommand;
+mod generate_vanity_implicit_subcommand;
 mod ledger_publickey_subcommand;
 mod send_signed_transaction;
 mod sign_transaction_subcommand_with_secret_key;
@@ -43,13 +46,13 @@ impl From<Utils> for CliUtils {
     }
 }
 
-impl From<CliUtils> for Utils {
-    fn from(item: CliUtils) -> Self {
+impl Utils {
+    pub fn from(item: CliUtils) -> color_eyre::eyre::Result<Self> {
         let util = match item.util {
-            Some(cli_util) => Util::from(cli_util),
-            None => Util::choose_util(),
+            Some(cli_util) => Util::from(cli_util)?,
+            None => Util::choose_util()?,
         };
-        Self { util }
+        Ok(Self { util })
     }
 }
 
@@ -63,6 +66,10 @@ impl Utils {
 enum CliUtil {
     /// It generates a random key pair
     GenerateKeypair(self::generate_keypair_subcommand::CliGenerateKeypair),
+    /// Derive the keys of a range of HD indexes from a seed phrase
+    DeriveKeys(self::derive_keys_subcommand::CliDeriveKeys),
+    /// Search for a key pair whose implicit account ID has the given prefix or suffix
+    GenerateVanityImplicit(self::generate_vanity_implicit_subcommand::CliGenerateVanityImplicit),
     /// Предоставьте данные для подписания данных с помощью private key
     SignTransactionPrivateKey(
         self::sign_transaction_subcommand_with_secret_key::CliSignTransactionPrivateKey,
@@ -81,6 +88,8 @@ enum CliUtil {
     LedgerPublicKey(self::ledger_publickey_subcommand::CliLedgerPublicKey),
     /// Send signed transaction
     SendSignedTransaction(self::send_signed_transaction::operation_mode::CliOperationMode),
+    /// Encrypt the plaintext key files of the keychain with a passphrase
+    EncryptKeychain(self::encrypt_keychain_subcommand::CliEncryptKeychain),
 }
 
 #[derive(Debug, Clone, EnumDiscriminants)]
@@ -88,6 +97,14 @@ enum CliUtil {
 pub enum Util {
     #[strum_discriminants(strum(message = "Generate a key pair"))]
     GenerateKeypair(self::generate_keypair_subcommand::CliGenerateKeypair),
+    #[strum_discriminants(strum(
+        message = "Derive the keys of a range of HD indexes from a seed phrase"
+    ))]
+    DeriveKeys(self::derive_keys_subcommand::DeriveKeys),
+    #[strum_discriminants(strum(
+        message = "Generate a key pair with a vanity implicit account ID"
+    ))]
+    GenerateVanityImplicit(self::generate_vanity_implicit_subcommand::GenerateVanityImplicit),
     #[strum_discriminants(strum(message = "Sign a transaction with private key"))]
     SignTransactionPrivateKey(
         self::sign_transaction_subcommand_with_secret_key::SignTransactionPrivateKey,
@@ -106,6 +123,8 @@ pub enum Util {
     LedgerPublicKey(self::ledger_publickey_subcommand::CliLedgerPublicKey),
     #[strum_discriminants(strum(message = "Send signed transaction"))]
     SendSignedTransaction(self::send_signed_transaction::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Encrypt the key files of the keychain"))]
+    EncryptKeychain(self::encrypt_keychain_subcommand::CliEncryptKeychain),
 }
 
 impl CliUtil {
@@ -116,6 +135,16 @@ impl CliUtil {
                 args.push_front("generate-keypair".to_owned());
                 args
             }
+            Self::DeriveKeys(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("derive-keys".to_owned());
+                args
+            }
+            Self::GenerateVanityImplicit(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("generate-vanity-implicit".to_owned());
+                args
+            }
             Self::SignTransactionPrivateKey(subcommand) => {
                 let mut args = subcommand.to_cli_args();
                 args.push_front("sign-transaction-private-key".to_owned());
@@ -146,6 +175,11 @@ impl CliUtil {
                 args.push_front("send-signed-transaction".to_owned());
                 args
             }
+            Self::EncryptKeychain(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("encrypt-keychain".to_owned());
+                args
+            }
         }
     }
 }
@@ -154,6 +188,10 @@ impl From<Util> for CliUtil {
     fn from(util: Util) -> Self {
         match util {
             Util::GenerateKeypair(generate_keypair) => Self::GenerateKeypair(generate_keypair),
+            Util::DeriveKeys(derive_keys) => Self::DeriveKeys(derive_keys.into()),
+            Util::GenerateVanityImplicit(generate_vanity_implicit) => {
+                Self::GenerateVanityImplicit(generate_vanity_implicit.into())
+            }
             Util::SignTransactionPrivateKey(sign_transaction_secret_key) => {
                 Self::SignTransactionPrivateKey(sign_transaction_secret_key.into())
             }
@@ -170,54 +208,75 @@ impl From<Util> for CliUtil {
             Util::SendSignedTransaction(operation_mode) => {
                 Self::SendSignedTransaction(operation_mode.into())
             }
+            Util::EncryptKeychain(encrypt_keychain) => Self::EncryptKeychain(encrypt_keychain),
         }
     }
 }
 
-impl From<CliUtil> for Util {
-    fn from(item: CliUtil) -> Self {
+impl Util {
+    pub fn from(item: CliUtil) -> color_eyre::eyre::Result<Self> {
         match item {
-            CliUtil::GenerateKeypair(generate_keypair) => Util::GenerateKeypair(generate_keypair),
+            CliUtil::GenerateKeypair(generate_keypair) => {
+                Ok(Util::GenerateKeypair(generate_keypair))
+            }
+            CliUtil::DeriveKeys(cli_derive_keys) => Ok(Util::DeriveKeys(
+                self::derive_keys_subcommand::DeriveKeys::from(cli_derive_keys)?,
+            )),
+            CliUtil::GenerateVanityImplicit(cli_generate_vanity_implicit) => {
+                Ok(Util::GenerateVanityImplicit(
+                    self::generate_vanity_implicit_subcommand::GenerateVanityImplicit::from(
+                        cli_generate_vanity_implicit,
+                    )?,
+                ))
+            }
             CliUtil::SignTransactionPrivateKey(cli_sign_transaction) => {
                 let sign_transaction =
-                    self::sign_transaction_subcommand_with_secret_key::SignTransactionPrivateKey::from(cli_sign_transaction);
-                Util::SignTransactionPrivateKey(sign_transaction)
+                    self::sign_transaction_subcommand_with_secret_key::SignTransactionPrivateKey::from(cli_sign_transaction)?;
+                Ok(Util::SignTransactionPrivateKey(sign_transaction))
             }
             CliUtil::SignTransactionWithLedger(cli_sign_transaction_with_ledger) => {
                 let sign_transaction =
                     self::sign_transaction_with_ledger_subcommand::SignTransactionWithLedger::from(
                         cli_sign_transaction_with_ledger,
-                    );
-                Util::SignTransactionWithLedger(sign_transaction)
+                    )?;
+                Ok(Util::SignTransactionWithLedger(sign_transaction))
             }
             CliUtil::CombineTransactionSignature(cli_combine_transaction) => {
                 let combine_transaction =
-                    self::combine_transaction_subcommand_with_signature::CombineTransactionSignature::from(cli_combine_transaction);
-                Util::CombineTransactionSignature(combine_transaction)
+                    self::combine_transaction_subcommand_with_signature::CombineTransactionSignature::from(cli_combine_transaction)?;
+                Ok(Util::CombineTransactionSignature(combine_transaction))
             }
             CliUtil::ViewSerializedTransaction(cli_view_serialized_transaction) => {
                 let view_serialized_transaction =
                     self::view_serialized_transaction::ViewSerializedTransaction::from(
                         cli_view_serialized_transaction,
-                    );
-                Util::ViewSerializedTransaction(view_serialized_transaction)
+                    )?;
+                Ok(Util::ViewSerializedTransaction(view_serialized_transaction))
+            }
+            CliUtil::LedgerPublicKey(ledger_publickey) => {
+                Ok(Util::LedgerPublicKey(ledger_publickey))
             }
-            CliUtil::LedgerPublicKey(ledger_publickey) => Util::LedgerPublicKey(ledger_publickey),
-            CliUtil::SendSignedTransaction(cli_operation_mode) => {
-                Util::SendSignedTransaction(cli_operation_mode.into())
+            CliUtil::SendSignedTransaction(cli_operation_mode) => Ok(Util::SendSignedTransaction(
+                self::send_signed_transaction::operation_mode::OperationMode::from(
+                    cli_operation_mode,
+                )?,
+            )),
+            CliUtil::EncryptKeychain(encrypt_keychain) => {
+                Ok(Util::EncryptKeychain(encrypt_keychain))
             }
         }
     }
 }
 
 impl Util {
-    fn choose_util() -> Self {
+    fn choose_util() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = UtilDiscriminants::iter().collect::<Vec<_>>();
         let utils = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("util")?;
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Choose your action")
             .items(&utils)
@@ -228,6 +287,10 @@ impl Util {
             UtilDiscriminants::GenerateKeypair => CliUtil::GenerateKeypair(
                 self::generate_keypair_subcommand::CliGenerateKeypair::default(),
             ),
+            UtilDiscriminants::DeriveKeys => CliUtil::DeriveKeys(Default::default()),
+            UtilDiscriminants::GenerateVanityImplicit => {
+                CliUtil::GenerateVanityImplicit(Default::default())
+            }
             UtilDiscriminants::SignTransactionPrivateKey => {
                 CliUtil::SignTransactionPrivateKey(Default::default())
             }
@@ -246,6 +309,7 @@ impl Util {
             UtilDiscriminants::SendSignedTransaction => {
                 CliUtil::SendSignedTransaction(Default::default())
             }
+            UtilDiscriminants::EncryptKeychain => CliUtil::EncryptKeychain(Default::default()),
         };
         Self::from(cli_util)
     }
@@ -253,6 +317,10 @@ impl Util {
     pub async fn process(self) -> crate::CliResult {
         match self {
             Self::GenerateKeypair(generate_keypair) => generate_keypair.process().await,
+            Self::DeriveKeys(derive_keys) => derive_keys.process().await,
+            Self::GenerateVanityImplicit(generate_vanity_implicit) => {
+                generate_vanity_implicit.process().await
+            }
             Self::SignTransactionPrivateKey(sign_transaction) => sign_transaction.process().await,
             Self::SignTransactionWithLedger(sign_transaction) => sign_transaction.process().await,
             Self::CombineTransactionSignature(combine_transaction) => {
@@ -263,6 +331,7 @@ impl Util {
             }
             Self::LedgerPublicKey(ledger_publickey) => ledger_publickey.process().await,
             Self::SendSignedTransaction(operation_mode) => operation_mode.process().await,
+            Self::EncryptKeychain(encrypt_keychain) => encrypt_keychain.process().await,
         }
     }
 }
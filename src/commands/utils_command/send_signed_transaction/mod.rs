@@ -1,4 +1,5 @@
 use dialoguer::Input;
+use near_primitives::borsh::BorshDeserialize;
 
 pub mod operation_mode;
 
@@ -30,22 +31,23 @@ impl From<Transaction> for CliTransaction {
     }
 }
 
-impl From<CliTransaction> for Transaction {
-    fn from(item: CliTransaction) -> Self {
+impl Transaction {
+    pub fn from(item: CliTransaction) -> color_eyre::eyre::Result<Self> {
         let transaction = match item.transaction {
             Some(transaction) => transaction,
-            None => Transaction::input_transaction(),
+            None => Transaction::input_transaction()?,
         };
-        Self { transaction }
+        Ok(Self { transaction })
     }
 }
 
 impl Transaction {
-    fn input_transaction() -> String {
-        Input::new()
+    fn input_transaction() -> color_eyre::eyre::Result<String> {
+        crate::common::ensure_interactive("transaction")?;
+        Ok(Input::new()
             .with_prompt("Enter the signed transaction hash you want to send")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -53,30 +55,19 @@ impl Transaction {
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
         println!("Transaction sent ...");
-        let json_rcp_client =
-            near_jsonrpc_client::new_client(network_connection_config.rpc_url().as_str());
-        let transaction_info = loop {
-            let transaction_info_result = json_rcp_client
-                .broadcast_tx_commit(self.transaction.clone())
-                .await;
-            match transaction_info_result {
-                Ok(response) => {
-                    break response;
-                }
-                Err(err) => {
-                    if let Some(serde_json::Value::String(data)) = &err.data {
-                        if data.contains("Timeout") {
-                            println!("Timeout error transaction.\nPlease wait. The next try to send this transaction is happening right now ...");
-                            continue;
-                        }
-                    }
-                    return Err(color_eyre::Report::msg(format!(
-                        "Error transaction: {:?}",
-                        err
-                    )));
-                }
-            };
-        };
+        let signed_transaction = near_primitives::transaction::SignedTransaction::try_from_slice(
+            &near_primitives::serialize::from_base64(&self.transaction).map_err(|err| {
+                color_eyre::Report::msg(format!("base64 transaction sequence is invalid: {}", err))
+            })?,
+        )
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Signed transaction could not be parsed: {}", err))
+        })?;
+        let transaction_info = network_connection_config
+            .rpc_client()
+            .broadcast_tx_commit(&signed_transaction)
+            .await
+            .map_err(|err| color_eyre::Report::msg(format!("Error transaction: {:?}", err)))?;
         crate::common::print_transaction_status(transaction_info, Some(network_connection_config))
             .await;
         Ok(())
@@ -8,8 +8,8 @@ pub mod select_server;
     setting(clap::AppSettings::VersionlessSubcommands)
 )]
 pub struct CliNetworkArgs {
-    #[clap(subcommand)]
-    selected_server: Option<self::select_server::CliSelectServer>,
+    #[clap(flatten)]
+    selected_server: self::select_server::CliSelectServer,
 }
 
 #[derive(Debug, Clone)]
@@ -19,30 +19,22 @@ pub struct NetworkArgs {
 
 impl CliNetworkArgs {
     pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
-        self.selected_server
-            .as_ref()
-            .map(|subcommand| subcommand.to_cli_args())
-            .unwrap_or_default()
+        self.selected_server.to_cli_args()
     }
 }
 
 impl From<NetworkArgs> for CliNetworkArgs {
     fn from(network_args: NetworkArgs) -> Self {
         Self {
-            selected_server: Some(network_args.selected_server.into()),
+            selected_server: network_args.selected_server.into(),
         }
     }
 }
 
-impl From<CliNetworkArgs> for NetworkArgs {
-    fn from(item: CliNetworkArgs) -> Self {
-        let selected_server = match item.selected_server {
-            Some(cli_selected_server) => {
-                self::select_server::SelectServer::from(cli_selected_server)
-            }
-            None => self::select_server::SelectServer::choose_server(),
-        };
-        Self { selected_server }
+impl NetworkArgs {
+    pub fn from(item: CliNetworkArgs) -> color_eyre::eyre::Result<Self> {
+        let selected_server = self::select_server::SelectServer::from(item.selected_server)?;
+        Ok(Self { selected_server })
     }
 }
 
@@ -1,6 +1,3 @@
-use dialoguer::Input;
-use std::str::FromStr;
-
 /// предустановленный RPC-сервер
 #[derive(Debug, Default, Clone, clap::Clap)]
 pub struct CliServer {
@@ -8,50 +5,12 @@ pub struct CliServer {
     send: Option<CliSend>,
 }
 
-/// данные для custom server
-#[derive(Debug, Default, Clone, clap::Clap)]
-pub struct CliCustomServer {
-    #[clap(long)]
-    pub url: Option<crate::common::AvailableRpcServerUrl>,
-    #[clap(subcommand)]
-    send: Option<CliSend>,
-}
-
 #[derive(Debug, Clone)]
 pub struct Server {
     pub connection_config: crate::common::ConnectionConfig,
     send: Send,
 }
 
-impl CliCustomServer {
-    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
-        let mut args = self
-            .send
-            .as_ref()
-            .map(|subcommand| subcommand.to_cli_args())
-            .unwrap_or_default();
-        if let Some(url) = &self.url {
-            args.push_front(url.to_string());
-            args.push_front("--url".to_string());
-        }
-        args
-    }
-}
-
-impl From<Server> for CliCustomServer {
-    fn from(server: Server) -> Self {
-        Self {
-            url: Some(
-                crate::common::AvailableRpcServerUrl::from_str(
-                    server.connection_config.rpc_url().as_str(),
-                )
-                .unwrap(),
-            ),
-            send: Some(server.send.into()),
-        }
-    }
-}
-
 impl CliServer {
     pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
         self.send
@@ -70,35 +29,18 @@ impl From<Server> for CliServer {
 }
 
 impl CliServer {
-    pub fn into_server(self, connection_config: crate::common::ConnectionConfig) -> Server {
+    pub fn into_server(
+        self,
+        connection_config: crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Server> {
         let send = match self.send {
-            Some(cli_send) => Send::from(cli_send),
-            None => Send::send(),
+            Some(cli_send) => Send::from(cli_send)?,
+            None => Send::send()?,
         };
-        Server {
+        Ok(Server {
             connection_config,
             send,
-        }
-    }
-}
-
-impl CliCustomServer {
-    pub fn into_server(self) -> Server {
-        let url: crate::common::AvailableRpcServerUrl = match self.url {
-            Some(url) => url,
-            None => Input::new()
-                .with_prompt("What is the RPC endpoint?")
-                .interact_text()
-                .unwrap(),
-        };
-        let send = match self.send {
-            Some(cli_send) => Send::from(cli_send),
-            None => Send::send(),
-        };
-        Server {
-            connection_config: crate::common::ConnectionConfig::Custom { url: url.inner },
-            send,
-        }
+        })
     }
 }
 
@@ -139,19 +81,19 @@ impl From<Send> for CliSend {
     }
 }
 
-impl From<CliSend> for Send {
-    fn from(item: CliSend) -> Self {
+impl Send {
+    pub fn from(item: CliSend) -> color_eyre::eyre::Result<Self> {
         match item {
             CliSend::Transaction(cli_transaction) => {
-                let transaction = super::super::super::super::Transaction::from(cli_transaction);
-                Self::Transaction(transaction)
+                let transaction = super::super::super::super::Transaction::from(cli_transaction)?;
+                Ok(Self::Transaction(transaction))
             }
         }
     }
 }
 
 impl Send {
-    fn send() -> Self {
+    fn send() -> color_eyre::eyre::Result<Self> {
         Self::from(CliSend::Transaction(Default::default()))
     }
 
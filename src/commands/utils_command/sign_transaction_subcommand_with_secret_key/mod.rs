@@ -4,15 +4,16 @@ use near_primitives::borsh::BorshSerialize;
 /// утилита, позволяющая подписать транзакцию личным ключом
 #[derive(Debug, Default, Clone, clap::Clap)]
 pub struct CliSignTransactionPrivateKey {
+    /// the private key itself, @path/to/file, - (stdin) or env:VAR_NAME
     #[clap(long)]
-    signer_private_key: Option<near_crypto::SecretKey>,
+    signer_private_key: Option<crate::common::SecretArg<near_crypto::SecretKey>>,
     #[clap(long)]
     unsigned_transaction: Option<crate::common::TransactionAsBase64>,
 }
 
 #[derive(Debug, Clone)]
 pub struct SignTransactionPrivateKey {
-    pub signer_private_key: near_crypto::SecretKey,
+    pub signer_private_key: crate::common::SecretArg<near_crypto::SecretKey>,
     pub unsigned_transaction: near_primitives::transaction::Transaction,
 }
 
@@ -48,48 +49,62 @@ impl From<SignTransactionPrivateKey> for CliSignTransactionPrivateKey {
     }
 }
 
-impl From<CliSignTransactionPrivateKey> for SignTransactionPrivateKey {
-    fn from(item: CliSignTransactionPrivateKey) -> Self {
-        let signer_private_key: near_crypto::SecretKey = match item.signer_private_key {
+impl SignTransactionPrivateKey {
+    pub fn from(item: CliSignTransactionPrivateKey) -> color_eyre::eyre::Result<Self> {
+        let signer_private_key = match item.signer_private_key {
             Some(cli_signer_private_key) => cli_signer_private_key,
-            None => SignTransactionPrivateKey::input_signer_private_key(),
+            None => SignTransactionPrivateKey::input_signer_private_key()?.into(),
         };
         let unsigned_transaction: near_primitives::transaction::Transaction =
             match item.unsigned_transaction {
                 Some(cli_unsigned_transaction) => cli_unsigned_transaction.inner,
-                None => SignTransactionPrivateKey::input_unsigned_transaction(),
+                None => SignTransactionPrivateKey::input_unsigned_transaction()?,
             };
-        SignTransactionPrivateKey {
+        Ok(SignTransactionPrivateKey {
             signer_private_key,
             unsigned_transaction,
-        }
+        })
     }
 }
 
 impl SignTransactionPrivateKey {
-    pub fn input_signer_private_key() -> near_crypto::SecretKey {
-        Input::new()
-            .with_prompt("Enter the private key")
-            .interact_text()
-            .unwrap()
+    pub fn input_signer_private_key() -> color_eyre::eyre::Result<near_crypto::SecretKey> {
+        crate::common::ensure_interactive("signer-private-key")?;
+        Ok(crate::common::input_secret("Enter the private key"))
     }
 
-    pub fn input_unsigned_transaction() -> near_primitives::transaction::Transaction {
+    pub fn input_unsigned_transaction(
+    ) -> color_eyre::eyre::Result<near_primitives::transaction::Transaction> {
+        crate::common::ensure_interactive("unsigned-transaction")?;
         let input: crate::common::TransactionAsBase64 = Input::new()
             .with_prompt("Enter an unsigned transaction")
             .interact_text()
             .unwrap();
-        input.inner
+        Ok(input.inner)
     }
 
-    pub async fn process(self) -> crate::CliResult {
+    /// Signs the transaction with an ed25519 or a secp256k1 key; the transaction has to name the
+    /// public key of the signing key.
+    fn sign(&self) -> color_eyre::eyre::Result<near_primitives::transaction::SignedTransaction> {
+        let signer_public_key = self.signer_private_key.inner.public_key();
+        if self.unsigned_transaction.public_key != signer_public_key {
+            return Err(color_eyre::Report::msg(format!(
+                "The transaction is prepared for the key {}, but the private key is of {}",
+                self.unsigned_transaction.public_key, signer_public_key
+            )));
+        }
         let signature = self
             .signer_private_key
+            .inner
             .sign(&self.unsigned_transaction.get_hash_and_size().0.as_ref());
-        let signed_transaction = near_primitives::transaction::SignedTransaction::new(
+        Ok(near_primitives::transaction::SignedTransaction::new(
             signature,
-            self.unsigned_transaction,
-        );
+            self.unsigned_transaction.clone(),
+        ))
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let signed_transaction = self.sign()?;
         let serialize_to_base64 = near_primitives::serialize::to_base64(
             signed_transaction.try_to_vec().map_err(|err| {
                 color_eyre::Report::msg(format!(
@@ -102,6 +117,14 @@ impl SignTransactionPrivateKey {
         println!("Signed transaction:");
         crate::common::print_transaction(signed_transaction.transaction.clone());
         println!("{:<13} {}", "signature:", signed_transaction.signature);
+        crate::common::add_json_output(
+            "signature",
+            serde_json::json!(signed_transaction.signature),
+        );
+        crate::common::add_json_output(
+            "signed_transaction_base64",
+            serde_json::json!(serialize_to_base64),
+        );
         println!(
             "Base64-encoded signed transaction:\n{}",
             serialize_to_base64
@@ -109,3 +132,57 @@ impl SignTransactionPrivateKey {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_primitives::borsh::BorshDeserialize;
+
+    #[test]
+    fn sign_and_verify_with_both_key_types() {
+        for key_type in [
+            near_crypto::KeyType::ED25519,
+            near_crypto::KeyType::SECP256K1,
+        ]
+        .iter()
+        {
+            let signer_private_key = near_crypto::SecretKey::from_random(*key_type);
+            let sign_transaction = SignTransactionPrivateKey {
+                unsigned_transaction: near_primitives::transaction::Transaction {
+                    signer_id: "alice.testnet".parse().unwrap(),
+                    public_key: signer_private_key.public_key(),
+                    nonce: 1,
+                    receiver_id: "bob.testnet".parse().unwrap(),
+                    block_hash: Default::default(),
+                    actions: vec![near_primitives::transaction::Action::Transfer(
+                        near_primitives::transaction::TransferAction { deposit: 1 },
+                    )],
+                },
+                signer_private_key: signer_private_key.clone().into(),
+            };
+            let signed_transaction = sign_transaction.sign().unwrap();
+            assert_eq!(signed_transaction.signature.key_type(), *key_type);
+
+            // The signature survives the base64 round trip and verifies against the public key
+            let decoded = near_primitives::transaction::SignedTransaction::try_from_slice(
+                &signed_transaction.try_to_vec().unwrap(),
+            )
+            .unwrap();
+            assert!(decoded.signature.verify(
+                decoded.get_hash().as_ref(),
+                &signer_private_key.public_key()
+            ));
+
+            let other_key = near_crypto::SecretKey::from_random(*key_type);
+            assert!(!decoded
+                .signature
+                .verify(decoded.get_hash().as_ref(), &other_key.public_key()));
+            assert!(SignTransactionPrivateKey {
+                signer_private_key: other_key.into(),
+                ..sign_transaction
+            }
+            .sign()
+            .is_err());
+        }
+    }
+}
@@ -48,39 +48,42 @@ impl From<SignTransactionWithLedger> for CliSignTransactionWithLedger {
     }
 }
 
-impl From<CliSignTransactionWithLedger> for SignTransactionWithLedger {
-    fn from(item: CliSignTransactionWithLedger) -> Self {
+impl SignTransactionWithLedger {
+    pub fn from(item: CliSignTransactionWithLedger) -> color_eyre::eyre::Result<Self> {
         let seed_phrase_hd_path = match item.seed_phrase_hd_path {
             Some(hd_path) => hd_path,
-            None => SignTransactionWithLedger::input_seed_phrase_hd_path(),
+            None => SignTransactionWithLedger::input_seed_phrase_hd_path()?,
         };
         let unsigned_transaction: near_primitives::transaction::Transaction =
             match item.unsigned_transaction {
                 Some(cli_unsigned_transaction) => cli_unsigned_transaction.inner,
-                None => SignTransactionWithLedger::input_unsigned_transaction(),
+                None => SignTransactionWithLedger::input_unsigned_transaction()?,
             };
-        SignTransactionWithLedger {
+        Ok(SignTransactionWithLedger {
             seed_phrase_hd_path,
             unsigned_transaction,
-        }
+        })
     }
 }
 
 impl SignTransactionWithLedger {
-    pub fn input_unsigned_transaction() -> near_primitives::transaction::Transaction {
+    pub fn input_unsigned_transaction(
+    ) -> color_eyre::eyre::Result<near_primitives::transaction::Transaction> {
+        crate::common::ensure_interactive("unsigned-transaction")?;
         let input: crate::common::TransactionAsBase64 = Input::new()
             .with_prompt("Enter an unsigned transaction")
             .interact_text()
             .unwrap();
-        input.inner
+        Ok(input.inner)
     }
 
-    pub fn input_seed_phrase_hd_path() -> slip10::BIP32Path {
-        Input::new()
+    pub fn input_seed_phrase_hd_path() -> color_eyre::eyre::Result<slip10::BIP32Path> {
+        crate::common::ensure_interactive("seed-phrase-hd-path")?;
+        Ok(Input::new()
             .with_prompt("Enter seed phrase HD Path (if you not sure leave blank for default)")
             .with_initial_text("44'/397'/0'/0'/1'")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(self) -> crate::CliResult {
@@ -124,6 +127,14 @@ impl SignTransactionWithLedger {
                 .try_to_vec()
                 .expect("Signed transaction is not expected to fail on serialization"),
         );
+        crate::common::add_json_output(
+            "signature",
+            serde_json::json!(signed_transaction.signature),
+        );
+        crate::common::add_json_output(
+            "signed_transaction_base64",
+            serde_json::json!(serialize_to_base64),
+        );
         println!(
             "Base64-encoded signed transaction:\n{}",
             serialize_to_base64
@@ -30,29 +30,36 @@ impl From<ViewSerializedTransaction> for CliViewSerializedTransaction {
     }
 }
 
-impl From<CliViewSerializedTransaction> for ViewSerializedTransaction {
-    fn from(item: CliViewSerializedTransaction) -> Self {
+impl ViewSerializedTransaction {
+    pub fn from(item: CliViewSerializedTransaction) -> color_eyre::eyre::Result<Self> {
         let transaction: String = match item.transaction {
             Some(transaction) => transaction,
-            None => ViewSerializedTransaction::input_transaction(),
+            None => ViewSerializedTransaction::input_transaction()?,
         };
-        Self { transaction }
+        Ok(Self { transaction })
     }
 }
 
 impl ViewSerializedTransaction {
-    fn input_transaction() -> String {
-        Input::new()
+    fn input_transaction() -> color_eyre::eyre::Result<String> {
+        crate::common::ensure_interactive("transaction")?;
+        Ok(Input::new()
             .with_prompt("Enter the hash of the transaction")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(self) -> crate::CliResult {
         let serialize_from_base64 =
             near_primitives::serialize::from_base64(&self.transaction).unwrap();
         match near_primitives::transaction::Transaction::try_from_slice(&serialize_from_base64) {
-            Ok(transaction) => println!("\n{:#?}", &transaction),
+            Ok(transaction) => {
+                crate::common::add_json_output(
+                    "transaction",
+                    serde_json::to_value(&transaction).unwrap_or_default(),
+                );
+                println!("\n{:#?}", &transaction)
+            }
             Err(_) => {
                 match near_primitives::transaction::SignedTransaction::try_from_slice(
                     &serialize_from_base64,
@@ -60,6 +67,10 @@ impl ViewSerializedTransaction {
                     Ok(signed_transaction) => {
                         println!("\nSigned transaction:\n");
                         crate::common::print_transaction(signed_transaction.transaction.clone());
+                        crate::common::add_json_output(
+                            "signature",
+                            serde_json::json!(signed_transaction.signature),
+                        );
                         println!("{:<13} {}", "signature:", signed_transaction.signature)
                     }
                     Err(err) => {
@@ -44,13 +44,13 @@ impl From<ViewQueryRequest> for CliViewQueryRequest {
     }
 }
 
-impl From<CliViewQueryRequest> for ViewQueryRequest {
-    fn from(item: CliViewQueryRequest) -> Self {
+impl ViewQueryRequest {
+    pub fn from(item: CliViewQueryRequest) -> color_eyre::eyre::Result<Self> {
         let query = match item.query {
-            Some(cli_query_request) => QueryRequest::from(cli_query_request),
-            None => QueryRequest::choose_query_request(),
+            Some(cli_query_request) => QueryRequest::from(cli_query_request)?,
+            None => QueryRequest::choose_query_request()?,
         };
-        ViewQueryRequest { query }
+        Ok(ViewQueryRequest { query })
     }
 }
 
@@ -149,39 +149,48 @@ impl From<QueryRequest> for CliQueryRequest {
     }
 }
 
-impl From<CliQueryRequest> for QueryRequest {
-    fn from(item: CliQueryRequest) -> Self {
+impl QueryRequest {
+    pub fn from(item: CliQueryRequest) -> color_eyre::eyre::Result<Self> {
         match item {
             CliQueryRequest::AccountSummary(cli_operation_mode) => {
-                QueryRequest::AccountSummary(cli_operation_mode.into())
-            }
-            CliQueryRequest::ContractCode(cli_operation_mode) => {
-                QueryRequest::ContractCode(cli_operation_mode.into())
-            }
-            CliQueryRequest::ContractState(cli_operation_mode) => {
-                QueryRequest::ContractState(cli_operation_mode.into())
-            }
-            CliQueryRequest::Transaction(cli_operation_mode) => {
-                QueryRequest::Transaction(cli_operation_mode.into())
-            }
-            CliQueryRequest::Nonce(cli_operation_mode) => {
-                QueryRequest::Nonce(cli_operation_mode.into())
-            }
+                Ok(QueryRequest::AccountSummary(
+                    self::view_account::operation_mode::OperationMode::from(cli_operation_mode)?,
+                ))
+            }
+            CliQueryRequest::ContractCode(cli_operation_mode) => Ok(QueryRequest::ContractCode(
+                self::view_contract_code::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliQueryRequest::ContractState(cli_operation_mode) => Ok(QueryRequest::ContractState(
+                self::view_contract_state::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliQueryRequest::Transaction(cli_operation_mode) => Ok(QueryRequest::Transaction(
+                self::view_transaction_status::operation_mode::OperationMode::from(
+                    cli_operation_mode,
+                )?,
+            )),
+            CliQueryRequest::Nonce(cli_operation_mode) => Ok(QueryRequest::Nonce(
+                self::view_nonce::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
             CliQueryRequest::RecentBlockHash(cli_operation_mode) => {
-                QueryRequest::RecentBlockHash(cli_operation_mode.into())
+                Ok(QueryRequest::RecentBlockHash(
+                    self::view_recent_block_hash::operation_mode::OperationMode::from(
+                        cli_operation_mode,
+                    )?,
+                ))
             }
         }
     }
 }
 
 impl QueryRequest {
-    fn choose_query_request() -> Self {
+    fn choose_query_request() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = QueryRequestDiscriminants::iter().collect::<Vec<_>>();
         let requests = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("query-request")?;
         let selected_request = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Сhoose what you want to view")
             .items(&requests)
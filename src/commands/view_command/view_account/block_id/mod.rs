@@ -57,28 +57,29 @@ impl From<BlockId> for CliBlockId {
     }
 }
 
-impl From<CliBlockId> for BlockId {
-    fn from(item: CliBlockId) -> Self {
+impl BlockId {
+    pub fn from(item: CliBlockId) -> color_eyre::eyre::Result<Self> {
         match item {
-            CliBlockId::AtFinalBlock => Self::AtFinalBlock,
-            CliBlockId::AtBlockHeight(cli_block_id_height) => {
-                Self::AtBlockHeight(cli_block_id_height.into())
-            }
-            CliBlockId::AtBlockHash(cli_block_id_hash) => {
-                Self::AtBlockHash(cli_block_id_hash.into())
-            }
+            CliBlockId::AtFinalBlock => Ok(Self::AtFinalBlock),
+            CliBlockId::AtBlockHeight(cli_block_id_height) => Ok(Self::AtBlockHeight(
+                self::block_id_height::BlockIdHeight::from(cli_block_id_height)?,
+            )),
+            CliBlockId::AtBlockHash(cli_block_id_hash) => Ok(Self::AtBlockHash(
+                self::block_id_hash::BlockIdHash::from(cli_block_id_hash)?,
+            )),
         }
     }
 }
 
 impl BlockId {
-    pub fn choose_block_id() -> Self {
+    pub fn choose_block_id() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = BlockIdDiscriminants::iter().collect::<Vec<_>>();
         let blocks = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("block-id")?;
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Choose your action")
             .items(&blocks)
@@ -111,132 +112,247 @@ impl BlockId {
                     .await
             }
             Self::AtFinalBlock => {
-                self.display_account_info(sender_account_id.clone(), &network_connection_config)
-                    .await?;
-                self.display_access_key_list(sender_account_id.clone(), &network_connection_config)
-                    .await?;
-                Ok(())
+                display_account_summary(
+                    sender_account_id,
+                    network_connection_config.rpc_client(),
+                    near_primitives::types::Finality::Final.into(),
+                )
+                .await
             }
         }
     }
+}
 
-    fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
+/// Prints the balances, the storage, the contract and the access keys of the account at the block.
+async fn display_account_summary(
+    account_id: near_primitives::types::AccountId,
+    rpc_client: crate::rpc_client::RpcClient,
+    block_reference: near_primitives::types::BlockReference,
+) -> crate::CliResult {
+    let query_view_method_response = rpc_client
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference,
+            request: near_primitives::views::QueryRequest::ViewAccount {
+                account_id: account_id.clone(),
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to fetch query for view account: {:?}", err))
+        })?;
+    let account_view =
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewAccount(result) =
+            query_view_method_response.kind
+        {
+            result
+        } else {
+            return Err(color_eyre::Report::msg("Error call result"));
+        };
+    // The rest is queried at the same block, also when the final block was asked for
+    let block_reference = near_primitives::types::BlockReference::BlockId(
+        near_primitives::types::BlockId::Hash(query_view_method_response.block_hash),
+    );
+    let storage_amount_per_byte = match rpc_client.protocol_config(block_reference.clone()).await {
+        Ok(protocol_config) => protocol_config["runtime_config"]["storage_amount_per_byte"]
+            .as_str()
+            .and_then(|storage_amount_per_byte| storage_amount_per_byte.parse().ok()),
+        Err(err) => {
+            eprintln!("Failed to fetch the storage price: {:?}", err);
+            None
+        }
+    };
+    let balances = AccountBalances::new(&account_view, storage_amount_per_byte);
+
+    crate::common::add_json_output(
+        "account",
+        serde_json::json!({
+            "account_id": account_id,
+            "block_height": query_view_method_response.block_height,
+            "block_hash": query_view_method_response.block_hash,
+            "amount": account_view.amount.to_string(),
+            "locked": account_view.locked.to_string(),
+            "total": balances.total.to_string(),
+            "available": balances.available.to_string(),
+            "storage_usage": account_view.storage_usage,
+            "storage_amount_per_byte": storage_amount_per_byte.map(|amount| amount.to_string()),
+            "storage_locked": balances.storage_locked.map(|amount| amount.to_string()),
+            "code_hash": account_view.code_hash,
+        }),
+    );
+    println!(
+        "Account details for '{}' at block #{} ({})",
+        account_id, query_view_method_response.block_height, query_view_method_response.block_hash,
+    );
+    println!(
+        "{:<28} {}",
+        "Total balance:",
+        crate::common::NearBalance::from_yoctonear(balances.total)
+    );
+    println!(
+        "{:<28} {}",
+        "Locked (validator stake):",
+        crate::common::NearBalance::from_yoctonear(account_view.locked)
+    );
+    println!(
+        "{:<28} {}",
+        "Available:",
+        crate::common::NearBalance::from_yoctonear(balances.available)
+    );
+    match (balances.storage_locked, storage_amount_per_byte) {
+        (Some(storage_locked), Some(storage_amount_per_byte)) => println!(
+            "{:<28} {} bytes, which lock {} ({} yoctoNEAR per byte)",
+            "Storage used:",
+            account_view.storage_usage,
+            crate::common::NearBalance::from_yoctonear(storage_locked),
+            storage_amount_per_byte
+        ),
+        _ => println!(
+            "{:<28} {} bytes",
+            "Storage used:", account_view.storage_usage
+        ),
+    }
+    if account_view.code_hash == near_primitives::hash::CryptoHash::default() {
+        println!("{:<28} no contract", "Contract code SHA-256 (hex):");
+    } else {
+        println!(
+            "{:<28} {}",
+            "Contract code SHA-256 (hex):",
+            hex::encode(account_view.code_hash.as_ref())
+        );
     }
 
-    async fn display_account_info(
-        &self,
-        account_id: near_primitives::types::AccountId,
-        network_connection_config: &crate::common::ConnectionConfig,
-    ) -> crate::CliResult {
-        let query_view_method_response = self
-            .rpc_client(network_connection_config.rpc_url().as_str())
-            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
-                block_reference: near_primitives::types::Finality::Final.into(),
-                request: near_primitives::views::QueryRequest::ViewAccount {
-                    account_id: account_id.clone(),
-                },
-            })
-            .await
-            .map_err(|err| {
-                color_eyre::Report::msg(format!(
-                    "Failed to fetch query for view account: {:?}",
-                    err
-                ))
-            })?;
-        let account_view =
-            if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewAccount(result) =
-                query_view_method_response.kind
-            {
-                result
-            } else {
-                return Err(color_eyre::Report::msg(format!("Error call result")));
-            };
+    let query_view_method_response = rpc_client
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference,
+            request: near_primitives::views::QueryRequest::ViewAccessKeyList {
+                account_id: account_id.clone(),
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to fetch query for view key list: {:?}",
+                err
+            ))
+        })?;
+    let access_key_view =
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKeyList(result) =
+            query_view_method_response.kind
+        {
+            result
+        } else {
+            return Err(color_eyre::Report::msg("Error call result"));
+        };
 
+    crate::common::add_json_output(
+        "access_keys",
+        serde_json::to_value(&access_key_view.keys).unwrap_or_default(),
+    );
+    println!("\nNumber of access keys: {}", access_key_view.keys.len());
+    for (index, access_key) in access_key_view.keys.iter().enumerate() {
+        let permissions_message = match &access_key.access_key.permission {
+            near_primitives::views::AccessKeyPermissionView::FullAccess => "full access".to_owned(),
+            near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                allowance,
+                receiver_id,
+                method_names,
+            } => {
+                let allowance_message = match allowance {
+                    Some(amount) => format!(
+                        "with a remaining allowance of {}",
+                        crate::common::NearBalance::from_yoctonear(*amount)
+                    ),
+                    None => format!("with no limit"),
+                };
+                format!(
+                    "only do {:?} function calls on {} {}",
+                    method_names, receiver_id, allowance_message
+                )
+            }
+        };
         println!(
-            "Account details for '{}' at block #{} ({})\n\
-            Native account balance: {}\n\
-            Validator stake: {}\n\
-            Storage used by the account: {} bytes",
-            account_id,
-            query_view_method_response.block_height,
-            query_view_method_response.block_hash,
-            crate::common::NearBalance::from_yoctonear(account_view.amount),
-            crate::common::NearBalance::from_yoctonear(account_view.locked),
-            account_view.storage_usage
+            "{: >4}. {} (nonce: {}) is granted to {}",
+            index + 1,
+            access_key.public_key,
+            access_key.access_key.nonce,
+            permissions_message
         );
-        if account_view.code_hash == near_primitives::hash::CryptoHash::default() {
-            println!("Contract code is not deployed to this account.");
-        } else {
-            println!(
-                "Contract code SHA-256 checksum (hex): {}",
-                hex::encode(account_view.code_hash.as_ref())
-            );
-        }
-        Ok(())
     }
+    Ok(())
+}
 
-    async fn display_access_key_list(
-        &self,
-        account_id: near_primitives::types::AccountId,
-        network_connection_config: &crate::common::ConnectionConfig,
-    ) -> crate::CliResult {
-        let query_view_method_response = self
-            .rpc_client(network_connection_config.rpc_url().as_str())
-            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
-                block_reference: near_primitives::types::Finality::Final.into(),
-                request: near_primitives::views::QueryRequest::ViewAccessKeyList {
-                    account_id: account_id.clone(),
-                },
-            })
-            .await
-            .map_err(|err| {
-                color_eyre::Report::msg(format!(
-                    "Failed to fetch query for view key list: {:?}",
-                    err
-                ))
-            })?;
-        let access_key_view =
-            if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKeyList(result) =
-                query_view_method_response.kind
-            {
-                result
-            } else {
-                return Err(color_eyre::Report::msg(format!("Error call result")));
-            };
-
-        println!("Number of access keys: {}", access_key_view.keys.len());
-        for (index, access_key) in access_key_view.keys.iter().enumerate() {
-            let permissions_message = match &access_key.access_key.permission {
-                near_primitives::views::AccessKeyPermissionView::FullAccess => {
-                    "full access".to_owned()
-                }
-                near_primitives::views::AccessKeyPermissionView::FunctionCall {
-                    allowance,
-                    receiver_id,
-                    method_names,
-                } => {
-                    let allowance_message = match allowance {
-                        Some(amount) => format!(
-                            "with an allowance of {}",
-                            crate::common::NearBalance::from_yoctonear(*amount)
-                        ),
-                        None => format!("with no limit"),
-                    };
-                    format!(
-                        "only do {:?} function calls on {} {}",
-                        method_names, receiver_id, allowance_message
-                    )
-                }
-            };
-            println!(
-                "{: >4}. {} (nonce: {}) is granted to {}",
-                index + 1,
-                access_key.public_key,
-                access_key.access_key.nonce,
-                permissions_message
-            );
+/// The balances of an account. The storage it uses locks NEAR at the storage price; the
+/// validator stake covers the storage first, the rest is locked from the account balance.
+#[derive(Debug, PartialEq)]
+struct AccountBalances {
+    total: near_primitives::types::Balance,
+    storage_locked: Option<near_primitives::types::Balance>,
+    available: near_primitives::types::Balance,
+}
+
+impl AccountBalances {
+    fn new(
+        account_view: &near_primitives::views::AccountView,
+        storage_amount_per_byte: Option<near_primitives::types::Balance>,
+    ) -> Self {
+        let storage_locked = storage_amount_per_byte.map(|storage_amount_per_byte| {
+            storage_amount_per_byte.saturating_mul(account_view.storage_usage.into())
+        });
+        let locked_from_amount = storage_locked
+            .unwrap_or_default()
+            .saturating_sub(account_view.locked);
+        Self {
+            total: account_view.amount.saturating_add(account_view.locked),
+            storage_locked,
+            available: account_view.amount.saturating_sub(locked_from_amount),
         }
-        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn storage_locks_a_part_of_the_balance() {
+        const NEAR: near_primitives::types::Balance = 10u128.pow(24);
+        let account_view = |amount, locked, storage_usage| near_primitives::views::AccountView {
+            amount,
+            locked,
+            code_hash: Default::default(),
+            storage_usage,
+            storage_paid_at: 0,
+        };
+        // 10^19 yoctoNEAR per byte: 100 kB lock 1 NEAR
+        let storage_amount_per_byte = Some(10u128.pow(19));
+        assert_eq!(
+            AccountBalances::new(&account_view(5 * NEAR, 0, 100_000), storage_amount_per_byte),
+            AccountBalances {
+                total: 5 * NEAR,
+                storage_locked: Some(NEAR),
+                available: 4 * NEAR,
+            }
+        );
+        // The validator stake covers the storage
+        assert_eq!(
+            AccountBalances::new(
+                &account_view(5 * NEAR, 2 * NEAR, 100_000),
+                storage_amount_per_byte
+            ),
+            AccountBalances {
+                total: 7 * NEAR,
+                storage_locked: Some(NEAR),
+                available: 5 * NEAR,
+            }
+        );
+        // Without the storage price the whole balance is shown as available
+        assert_eq!(
+            AccountBalances::new(&account_view(5 * NEAR, 0, 100_000), None),
+            AccountBalances {
+                total: 5 * NEAR,
+                storage_locked: None,
+                available: 5 * NEAR,
+            }
+        );
     }
 }
@@ -31,19 +31,19 @@ impl From<SendTo> for CliSendTo {
     }
 }
 
-impl From<CliSendTo> for SendTo {
-    fn from(item: CliSendTo) -> Self {
+impl SendTo {
+    pub fn from(item: CliSendTo) -> color_eyre::eyre::Result<Self> {
         match item {
             CliSendTo::Account(cli_sender) => {
-                let sender = Sender::from(cli_sender);
-                Self::Account(sender)
+                let sender = Sender::from(cli_sender)?;
+                Ok(Self::Account(sender))
             }
         }
     }
 }
 
 impl SendTo {
-    pub fn send_to() -> Self {
+    pub fn send_to() -> color_eyre::eyre::Result<Self> {
         Self::from(CliSendTo::Account(Default::default()))
     }
 
@@ -99,30 +99,32 @@ impl From<Sender> for CliSender {
     }
 }
 
-impl From<CliSender> for Sender {
-    fn from(item: CliSender) -> Self {
+impl Sender {
+    pub fn from(item: CliSender) -> color_eyre::eyre::Result<Self> {
         let sender_account_id: near_primitives::types::AccountId = match item.sender_account_id {
             Some(cli_sender_account_id) => cli_sender_account_id,
-            None => Sender::input_sender_account_id(),
+            None => Sender::input_sender_account_id()?,
         };
         let selected_block_id: super::block_id::BlockId = match item.selected_block_id {
-            Some(cli_block_id) => cli_block_id.into(),
-            None => super::block_id::BlockId::choose_block_id(),
+            Some(cli_block_id) => super::block_id::BlockId::from(cli_block_id)?,
+            None => super::block_id::BlockId::choose_block_id()?,
         };
-        Self {
+        Ok(Self {
             sender_account_id,
             selected_block_id,
-        }
+        })
     }
 }
 
 impl Sender {
-    pub fn input_sender_account_id() -> near_primitives::types::AccountId {
+    pub fn input_sender_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId>
+    {
         println!();
-        Input::new()
+        crate::common::ensure_interactive("sender-account-id")?;
+        Ok(Input::new()
             .with_prompt("What Account ID do you need to view?")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -30,26 +30,23 @@ impl From<BlockIdHash> for CliBlockIdHash {
     }
 }
 
-impl From<CliBlockIdHash> for BlockIdHash {
-    fn from(item: CliBlockIdHash) -> Self {
+impl BlockIdHash {
+    pub fn from(item: CliBlockIdHash) -> color_eyre::eyre::Result<Self> {
         let block_id_hash: near_primitives::hash::CryptoHash = match item.block_id_hash {
             Some(cli_block_id_hash) => cli_block_id_hash,
-            None => BlockIdHash::input_block_id_hash(),
+            None => BlockIdHash::input_block_id_hash()?,
         };
-        Self { block_id_hash }
+        Ok(Self { block_id_hash })
     }
 }
 
 impl BlockIdHash {
-    pub fn input_block_id_hash() -> near_primitives::hash::CryptoHash {
-        Input::new()
+    pub fn input_block_id_hash() -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+        crate::common::ensure_interactive("block-id-hash")?;
+        Ok(Input::new()
             .with_prompt("Type the block ID hash for this contract")
             .interact_text()
-            .unwrap()
-    }
-
-    fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
+            .unwrap())
     }
 
     pub async fn process(
@@ -58,8 +55,8 @@ impl BlockIdHash {
         network_connection_config: crate::common::ConnectionConfig,
         file_path: Option<std::path::PathBuf>,
     ) -> crate::CliResult {
-        let query_view_method_response = self
-            .rpc_client(network_connection_config.archival_rpc_url().as_str())
+        let query_view_method_response = network_connection_config
+            .archival_rpc_client()
             .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
                 block_reference: near_primitives::types::BlockReference::BlockId(
                     near_primitives::types::BlockId::Hash(self.block_id_hash.clone()),
@@ -83,6 +80,13 @@ impl BlockIdHash {
             } else {
                 return Err(color_eyre::Report::msg(format!("Error call result")));
             };
+        crate::common::add_json_output(
+            "contract_code",
+            serde_json::json!({
+                "code_hash": call_access_view.hash,
+                "file": file_path,
+            }),
+        );
         match &file_path {
             Some(file_path) => {
                 let dir_name = &file_path.parent().unwrap();
@@ -58,28 +58,29 @@ impl From<BlockId> for CliBlockId {
     }
 }
 
-impl From<CliBlockId> for BlockId {
-    fn from(item: CliBlockId) -> Self {
+impl BlockId {
+    pub fn from(item: CliBlockId) -> color_eyre::eyre::Result<Self> {
         match item {
-            CliBlockId::AtFinalBlock => Self::AtFinalBlock,
-            CliBlockId::AtBlockHeight(cli_block_id_height) => {
-                Self::AtBlockHeight(cli_block_id_height.into())
-            }
-            CliBlockId::AtBlockHash(cli_block_id_hash) => {
-                Self::AtBlockHash(cli_block_id_hash.into())
-            }
+            CliBlockId::AtFinalBlock => Ok(Self::AtFinalBlock),
+            CliBlockId::AtBlockHeight(cli_block_id_height) => Ok(Self::AtBlockHeight(
+                self::block_id_height::BlockIdHeight::from(cli_block_id_height)?,
+            )),
+            CliBlockId::AtBlockHash(cli_block_id_hash) => Ok(Self::AtBlockHash(
+                self::block_id_hash::BlockIdHash::from(cli_block_id_hash)?,
+            )),
         }
     }
 }
 
 impl BlockId {
-    pub fn choose_block_id() -> Self {
+    pub fn choose_block_id() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = BlockIdDiscriminants::iter().collect::<Vec<_>>();
         let blocks = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("block-id")?;
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Choose your action")
             .items(&blocks)
@@ -119,18 +120,14 @@ impl BlockId {
         }
     }
 
-    fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
-    }
-
     async fn at_final_block(
         self,
         contract_id: near_primitives::types::AccountId,
         network_connection_config: crate::common::ConnectionConfig,
         file_path: Option<std::path::PathBuf>,
     ) -> crate::CliResult {
-        let query_view_method_response = self
-            .rpc_client(network_connection_config.rpc_url().as_str())
+        let query_view_method_response = network_connection_config
+            .rpc_client()
             .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
                 block_reference: near_primitives::types::Finality::Final.into(),
                 request: near_primitives::views::QueryRequest::ViewCode {
@@ -152,6 +149,13 @@ impl BlockId {
             } else {
                 return Err(color_eyre::Report::msg(format!("Error call result")));
             };
+        crate::common::add_json_output(
+            "contract_code",
+            serde_json::json!({
+                "code_hash": call_access_view.hash,
+                "file": file_path,
+            }),
+        );
         match &file_path {
             Some(file_path) => {
                 let dir_name = &file_path.parent().unwrap();
@@ -35,13 +35,13 @@ impl From<ContractHash> for CliContractHash {
 }
 
 impl ContractHash {
-    pub fn from(item: CliContractHash) -> Self {
+    pub fn from(item: CliContractHash) -> color_eyre::eyre::Result<Self> {
         let selected_block_id: super::super::super::block_id::BlockId = match item.selected_block_id
         {
-            Some(cli_block_id) => cli_block_id.into(),
-            None => super::super::super::block_id::BlockId::choose_block_id(),
+            Some(cli_block_id) => super::super::super::block_id::BlockId::from(cli_block_id)?,
+            None => super::super::super::block_id::BlockId::choose_block_id()?,
         };
-        ContractHash { selected_block_id }
+        Ok(ContractHash { selected_block_id })
     }
 }
 
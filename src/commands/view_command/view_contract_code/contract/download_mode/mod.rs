@@ -48,26 +48,27 @@ impl From<DownloadMode> for CliDownloadMode {
 }
 
 impl DownloadMode {
-    pub fn from(item: CliDownloadMode, contract_id: &str) -> Self {
+    pub fn from(item: CliDownloadMode, contract_id: &str) -> color_eyre::eyre::Result<Self> {
         match item {
-            CliDownloadMode::Download(cli_contract_file) => DownloadMode::Download(
-                self::download_contract::ContractFile::from(cli_contract_file, contract_id),
-            ),
-            CliDownloadMode::Hash(cli_contract_hash) => {
-                DownloadMode::Hash(self::hash_contract::ContractHash::from(cli_contract_hash))
-            }
+            CliDownloadMode::Download(cli_contract_file) => Ok(DownloadMode::Download(
+                self::download_contract::ContractFile::from(cli_contract_file, contract_id)?,
+            )),
+            CliDownloadMode::Hash(cli_contract_hash) => Ok(DownloadMode::Hash(
+                self::hash_contract::ContractHash::from(cli_contract_hash)?,
+            )),
         }
     }
 }
 
 impl DownloadMode {
-    pub fn choose_download_mode(contract_id: &str) -> Self {
+    pub fn choose_download_mode(contract_id: &str) -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = DownloadModeDiscriminants::iter().collect::<Vec<_>>();
         let modes = variants
             .iter()
             .map(|p| p.get_message().unwrap().to_owned())
             .collect::<Vec<_>>();
+        crate::common::ensure_interactive("download-mode")?;
         let selected_mode = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("To view contract code you will need to choose next action")
             .items(&modes)
@@ -33,19 +33,19 @@ impl From<SendTo> for CliSendTo {
     }
 }
 
-impl From<CliSendTo> for SendTo {
-    fn from(item: CliSendTo) -> Self {
+impl SendTo {
+    pub fn from(item: CliSendTo) -> color_eyre::eyre::Result<Self> {
         match item {
             CliSendTo::Contract(cli_sender) => {
-                let sender = Contract::from(cli_sender);
-                Self::Contract(sender)
+                let sender = Contract::from(cli_sender)?;
+                Ok(Self::Contract(sender))
             }
         }
     }
 }
 
 impl SendTo {
-    pub fn send_to() -> Self {
+    pub fn send_to() -> color_eyre::eyre::Result<Self> {
         Self::from(CliSendTo::Contract(Default::default()))
     }
 
@@ -101,34 +101,36 @@ impl From<Contract> for CliContract {
     }
 }
 
-impl From<CliContract> for Contract {
-    fn from(item: CliContract) -> Self {
+impl Contract {
+    pub fn from(item: CliContract) -> color_eyre::eyre::Result<Self> {
         let contract_id: near_primitives::types::AccountId = match item.contract_id {
             Some(cli_contract_id) => cli_contract_id,
-            None => Contract::input_contract_id(),
+            None => Contract::input_contract_id()?,
         };
         let download_mode = match item.download_mode {
-            Some(cli_download_mode) => {
-                self::download_mode::DownloadMode::from(cli_download_mode, &contract_id.to_string())
-            }
+            Some(cli_download_mode) => self::download_mode::DownloadMode::from(
+                cli_download_mode,
+                &contract_id.to_string(),
+            )?,
             None => {
-                self::download_mode::DownloadMode::choose_download_mode(&contract_id.to_string())
+                self::download_mode::DownloadMode::choose_download_mode(&contract_id.to_string())?
             }
         };
-        Self {
+        Ok(Self {
             contract_id,
             download_mode,
-        }
+        })
     }
 }
 
 impl Contract {
-    pub fn input_contract_id() -> near_primitives::types::AccountId {
+    pub fn input_contract_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
         println!();
-        Input::new()
+        crate::common::ensure_interactive("contract-id")?;
+        Ok(Input::new()
             .with_prompt("What contract do you need to view?")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
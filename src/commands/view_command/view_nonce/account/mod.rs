@@ -31,19 +31,19 @@ impl From<SendTo> for CliSendTo {
     }
 }
 
-impl From<CliSendTo> for SendTo {
-    fn from(item: CliSendTo) -> Self {
+impl SendTo {
+    pub fn from(item: CliSendTo) -> color_eyre::eyre::Result<Self> {
         match item {
             CliSendTo::Account(cli_account) => {
-                let account = Account::from(cli_account);
-                Self::Account(account)
+                let account = Account::from(cli_account)?;
+                Ok(Self::Account(account))
             }
         }
     }
 }
 
 impl SendTo {
-    pub fn send_to() -> Self {
+    pub fn send_to() -> color_eyre::eyre::Result<Self> {
         Self::from(CliSendTo::Account(Default::default()))
     }
 
@@ -99,30 +99,31 @@ impl From<Account> for CliAccount {
     }
 }
 
-impl From<CliAccount> for Account {
-    fn from(item: CliAccount) -> Self {
+impl Account {
+    pub fn from(item: CliAccount) -> color_eyre::eyre::Result<Self> {
         let account_id: near_primitives::types::AccountId = match item.account_id {
             Some(cli_account_id) => cli_account_id,
-            None => Account::input_account_id(),
+            None => Account::input_account_id()?,
         };
         let public_key = match item.public_key {
-            Some(cli_public_key) => super::public_key::AccessKey::from(cli_public_key),
-            None => super::public_key::AccessKey::choose_key(),
+            Some(cli_public_key) => super::public_key::AccessKey::from(cli_public_key)?,
+            None => super::public_key::AccessKey::choose_key()?,
         };
-        Self {
+        Ok(Self {
             account_id,
             public_key,
-        }
+        })
     }
 }
 
 impl Account {
-    fn input_account_id() -> near_primitives::types::AccountId {
+    fn input_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
         println!();
-        Input::new()
+        crate::common::ensure_interactive("account-id")?;
+        Ok(Input::new()
             .with_prompt("Enter your account ID")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
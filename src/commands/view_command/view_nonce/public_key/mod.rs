@@ -31,18 +31,18 @@ impl From<AccessKey> for CliAccessKey {
     }
 }
 
-impl From<CliAccessKey> for AccessKey {
-    fn from(item: CliAccessKey) -> Self {
+impl AccessKey {
+    pub fn from(item: CliAccessKey) -> color_eyre::eyre::Result<Self> {
         match item {
-            CliAccessKey::PublicKey(cli_delete_access_key_type) => {
-                Self::PublicKey(cli_delete_access_key_type.into())
-            }
+            CliAccessKey::PublicKey(cli_delete_access_key_type) => Ok(Self::PublicKey(
+                AccessKeyType::from(cli_delete_access_key_type)?,
+            )),
         }
     }
 }
 
 impl AccessKey {
-    pub fn choose_key() -> Self {
+    pub fn choose_key() -> color_eyre::eyre::Result<Self> {
         Self::from(CliAccessKey::PublicKey(Default::default()))
     }
 
@@ -90,26 +90,23 @@ impl From<AccessKeyType> for CliAccessKeyType {
     }
 }
 
-impl From<CliAccessKeyType> for AccessKeyType {
-    fn from(item: CliAccessKeyType) -> Self {
+impl AccessKeyType {
+    pub fn from(item: CliAccessKeyType) -> color_eyre::eyre::Result<Self> {
         let public_key: near_crypto::PublicKey = match item.public_key {
             Some(cli_public_key) => cli_public_key,
-            None => AccessKeyType::input_public_key(),
+            None => AccessKeyType::input_public_key()?,
         };
-        Self { public_key }
+        Ok(Self { public_key })
     }
 }
 
 impl AccessKeyType {
-    pub fn input_public_key() -> near_crypto::PublicKey {
-        Input::new()
+    pub fn input_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        crate::common::ensure_interactive("public-key")?;
+        Ok(Input::new()
             .with_prompt("Enter a public key for this access key")
             .interact_text()
-            .unwrap()
-    }
-
-    fn rpc_client(self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
+            .unwrap())
     }
 
     pub async fn process(
@@ -118,8 +115,8 @@ impl AccessKeyType {
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
         let public_key = self.public_key.clone();
-        let online_signer_access_key_response = self
-            .rpc_client(network_connection_config.rpc_url().as_str())
+        let online_signer_access_key_response = network_connection_config
+            .rpc_client()
             .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
                 block_reference: near_primitives::types::Finality::Final.into(),
                 request: near_primitives::views::QueryRequest::ViewAccessKey {
@@ -143,6 +140,13 @@ impl AccessKeyType {
             } else {
                 return Err(color_eyre::Report::msg(format!("Error current_nonce")));
             };
+        crate::common::add_json_output(
+            "access_key",
+            serde_json::json!({
+                "public_key": public_key,
+                "nonce": current_nonce,
+            }),
+        );
         println!(
             "\ncurrent nonce: {}  for a public key: {}",
             current_nonce, public_key
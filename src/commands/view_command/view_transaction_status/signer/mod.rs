@@ -31,19 +31,19 @@ impl From<SendFrom> for CliSendFrom {
     }
 }
 
-impl From<CliSendFrom> for SendFrom {
-    fn from(item: CliSendFrom) -> Self {
+impl SendFrom {
+    pub fn from(item: CliSendFrom) -> color_eyre::eyre::Result<Self> {
         match item {
             CliSendFrom::Signer(cli_sender) => {
-                let sender = Sender::from(cli_sender);
-                Self::Signer(sender)
+                let sender = Sender::from(cli_sender)?;
+                Ok(Self::Signer(sender))
             }
         }
     }
 }
 
 impl SendFrom {
-    pub fn send_from() -> Self {
+    pub fn send_from() -> color_eyre::eyre::Result<Self> {
         Self::from(CliSendFrom::Signer(Default::default()))
     }
 
@@ -91,27 +91,25 @@ impl From<Sender> for CliSender {
     }
 }
 
-impl From<CliSender> for Sender {
-    fn from(item: CliSender) -> Self {
+impl Sender {
+    pub fn from(item: CliSender) -> color_eyre::eyre::Result<Self> {
         let account_id: near_primitives::types::AccountId = match item.account_id {
             Some(cli_account_id) => cli_account_id,
-            None => Sender::input_sender_account_id(),
+            None => Sender::input_sender_account_id()?,
         };
-        Self { account_id }
+        Ok(Self { account_id })
     }
 }
 
 impl Sender {
-    pub fn input_sender_account_id() -> near_primitives::types::AccountId {
+    pub fn input_sender_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId>
+    {
         println!();
-        Input::new()
+        crate::common::ensure_interactive("sender-account-id")?;
+        Ok(Input::new()
             .with_prompt("Specify the account that signed the transaction")
             .interact_text()
-            .unwrap()
-    }
-
-    fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
+            .unwrap())
     }
 
     pub async fn process(
@@ -120,8 +118,8 @@ impl Sender {
         transaction_hash: String,
     ) -> crate::CliResult {
         let account_id = self.account_id.clone();
-        let query_view_transaction_status = self
-            .rpc_client(network_connection_config.archival_rpc_url().as_str())
+        let query_view_transaction_status = network_connection_config
+            .archival_rpc_client()
             .tx(transaction_hash, account_id)
             .await
             .map_err(|err| {
@@ -130,6 +128,10 @@ impl Sender {
                     err
                 ))
             })?;
+        crate::common::add_json_output(
+            "outcome",
+            serde_json::to_value(&query_view_transaction_status).unwrap_or_default(),
+        );
         println!("Transactiion status: {:#?}", query_view_transaction_status);
         Ok(())
     }
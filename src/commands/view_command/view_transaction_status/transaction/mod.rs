@@ -33,18 +33,18 @@ impl From<Transaction> for CliTransaction {
     }
 }
 
-impl From<CliTransaction> for Transaction {
-    fn from(item: CliTransaction) -> Self {
+impl Transaction {
+    pub fn from(item: CliTransaction) -> color_eyre::eyre::Result<Self> {
         match item {
-            CliTransaction::TransactionHash(cli_transaction_type) => {
-                Transaction::TransactionHash(cli_transaction_type.into())
-            }
+            CliTransaction::TransactionHash(cli_transaction_type) => Ok(
+                Transaction::TransactionHash(TransactionType::from(cli_transaction_type)?),
+            ),
         }
     }
 }
 
 impl Transaction {
-    pub fn transaction() -> Self {
+    pub fn transaction() -> color_eyre::eyre::Result<Self> {
         Self::from(CliTransaction::TransactionHash(Default::default()))
     }
 
@@ -102,30 +102,31 @@ impl From<TransactionType> for CliTransactionType {
     }
 }
 
-impl From<CliTransactionType> for TransactionType {
-    fn from(item: CliTransactionType) -> Self {
+impl TransactionType {
+    pub fn from(item: CliTransactionType) -> color_eyre::eyre::Result<Self> {
         let transaction_hash = match item.transaction_hash {
             Some(cli_transaction_hash) => cli_transaction_hash,
-            None => TransactionType::input_transaction_hash(),
+            None => TransactionType::input_transaction_hash()?,
         };
         let send_from = match item.send_from {
-            Some(cli_send_from) => super::signer::SendFrom::from(cli_send_from),
-            None => super::signer::SendFrom::send_from(),
+            Some(cli_send_from) => super::signer::SendFrom::from(cli_send_from)?,
+            None => super::signer::SendFrom::send_from()?,
         };
-        Self {
+        Ok(Self {
             transaction_hash,
             send_from,
-        }
+        })
     }
 }
 
 impl TransactionType {
-    fn input_transaction_hash() -> String {
+    fn input_transaction_hash() -> color_eyre::eyre::Result<String> {
         println!();
-        Input::new()
+        crate::common::ensure_interactive("transaction-hash")?;
+        Ok(Input::new()
             .with_prompt("Enter the hash of the transaction you need to view")
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -27,6 +27,239 @@ impl std::fmt::Display for OutputFormat {
     }
 }
 
+thread_local! {
+    /// The top-level `--output` format
+    static OUTPUT_FORMAT: std::cell::RefCell<OutputFormat> =
+        std::cell::RefCell::new(OutputFormat::Plaintext);
+    /// The fields of the JSON object printed at the end of the run with `--output json`
+    static JSON_OUTPUT: std::cell::RefCell<serde_json::Map<String, serde_json::Value>> =
+        std::cell::RefCell::new(serde_json::Map::new());
+}
+
+impl OutputFormat {
+    pub fn set_global(output_format: Self) {
+        OUTPUT_FORMAT.with(|global| *global.borrow_mut() = output_format);
+    }
+
+    pub fn global() -> Self {
+        OUTPUT_FORMAT.with(|global| global.borrow().clone())
+    }
+}
+
+thread_local! {
+    /// The command given on the command line, set when prompts are not allowed
+    static NON_INTERACTIVE_COMMAND: std::cell::RefCell<Option<String>> =
+        std::cell::RefCell::new(None);
+}
+
+/// Makes every following `ensure_interactive` call fail instead of letting the prompt wait for
+/// an answer that never comes (`--no-interactive` or stdin is not a terminal).
+pub fn disable_prompts(command: String) {
+    NON_INTERACTIVE_COMMAND
+        .with(|non_interactive_command| *non_interactive_command.borrow_mut() = Some(command));
+}
+
+/// Whether prompts are disabled, for the commands that can go on without an optional answer.
+pub fn prompts_disabled() -> bool {
+    NON_INTERACTIVE_COMMAND
+        .with(|non_interactive_command| non_interactive_command.borrow().is_some())
+}
+
+/// Called right before every prompt; in non-interactive mode it fails with the missing argument.
+pub fn ensure_interactive(missing_argument: &str) -> crate::CliResult {
+    NON_INTERACTIVE_COMMAND.with(|non_interactive_command| {
+        match &*non_interactive_command.borrow() {
+            Some(command) => Err(color_eyre::Report::msg(format!(
+                "<{}> is missing after `{}` and prompts are disabled (--no-interactive or stdin is not a terminal)",
+                missing_argument, command
+            ))),
+            None => Ok(()),
+        }
+    })
+}
+
+/// Adds a field to the JSON result of the command; with plaintext output it is a no-op, so
+/// commands call it next to their human-readable `println!`s.
+pub fn add_json_output(name: &str, value: serde_json::Value) {
+    if let OutputFormat::Json = OutputFormat::global() {
+        JSON_OUTPUT.with(|json_output| json_output.borrow_mut().insert(name.to_owned(), value));
+    }
+}
+
+pub fn take_json_output() -> serde_json::Value {
+    serde_json::Value::Object(JSON_OUTPUT.with(|json_output| json_output.take()))
+}
+
+/// With `--output json` stdout carries nothing but the JSON result, so all the human-readable
+/// text is sent to stderr instead. Returns a handle to the original stdout.
+#[cfg(unix)]
+pub fn redirect_stdout_to_stderr() -> std::io::Result<Box<dyn std::io::Write>> {
+    use std::os::unix::io::FromRawFd;
+
+    std::io::stdout().flush()?;
+    // SAFETY: only the process-wide stdout and stderr descriptors are touched and the duplicate
+    // of stdout is owned by the returned `File`
+    unsafe {
+        let json_stdout = libc::dup(libc::STDOUT_FILENO);
+        if json_stdout < 0 || libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        Ok(Box::new(std::fs::File::from_raw_fd(json_stdout)))
+    }
+}
+
+#[cfg(not(unix))]
+pub fn redirect_stdout_to_stderr() -> std::io::Result<Box<dyn std::io::Write>> {
+    Ok(Box::new(std::io::stdout()))
+}
+
+/// Echoed instead of private keys and seed phrases in "Your console command", which ends up in
+/// terminal scrollback and CI logs (see `SecretArg`).
+pub const SECRET_PLACEHOLDER: &str = "<secret>";
+
+/// A secret command line argument (a private key or a seed phrase). Besides the value itself it
+/// accepts `@path/to/file`, `-` to read it from stdin and `env:VAR_NAME`, so the secret does not
+/// have to appear in shell history and `ps` output.
+#[derive(Clone)]
+pub struct SecretArg<T> {
+    pub inner: T,
+    source: SecretSource,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum SecretSource {
+    Value,
+    File(std::path::PathBuf),
+    Stdin,
+    Env(String),
+}
+
+impl<T> From<T> for SecretArg<T> {
+    fn from(inner: T) -> Self {
+        Self {
+            inner,
+            source: SecretSource::Value,
+        }
+    }
+}
+
+impl<T> std::str::FromStr for SecretArg<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (secret, source) = if let Some(path) = s.strip_prefix('@') {
+            let secret = std::fs::read_to_string(path)
+                .map_err(|err| format!("Failed to read the secret from {}: {}", path, err))?;
+            (secret, SecretSource::File(path.into()))
+        } else if s == "-" {
+            let mut secret = String::new();
+            std::io::stdin()
+                .read_line(&mut secret)
+                .map_err(|err| format!("Failed to read the secret from stdin: {}", err))?;
+            (secret, SecretSource::Stdin)
+        } else if s == SECRET_PLACEHOLDER {
+            return Err(format!(
+                "{} stands for a secret hidden in the echoed command; give the secret itself, @path/to/file, - (stdin) or env:VAR_NAME",
+                SECRET_PLACEHOLDER
+            ));
+        } else if let Some(var_name) = s.strip_prefix("env:") {
+            let secret = std::env::var(var_name)
+                .map_err(|_| format!("Environment variable {} is not set", var_name))?;
+            (secret, SecretSource::Env(var_name.to_owned()))
+        } else {
+            (s.to_owned(), SecretSource::Value)
+        };
+        Ok(Self {
+            inner: secret.trim().parse().map_err(|err| format!("{}", err))?,
+            source,
+        })
+    }
+}
+
+/// Shows where the secret comes from, never the secret itself
+impl<T> std::fmt::Display for SecretArg<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.source {
+            SecretSource::Value => write!(f, "{}", SECRET_PLACEHOLDER),
+            SecretSource::File(path) => write!(f, "@{}", path.display()),
+            SecretSource::Stdin => write!(f, "-"),
+            SecretSource::Env(var_name) => write!(f, "env:{}", var_name),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for SecretArg<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecretArg({})", self)
+    }
+}
+
+/// Prompts for a secret without echoing the typed characters.
+pub fn input_secret<T>(prompt: &str) -> T
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    loop {
+        let secret = dialoguer::Password::new()
+            .with_prompt(prompt)
+            .interact()
+            .unwrap();
+        match secret.trim().parse() {
+            Ok(secret) => break secret,
+            Err(err) => println!("{}", err),
+        }
+    }
+}
+
+/// The optional BIP-39 passphrase (the "25th word") of a seed phrase
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliSeedPhrasePassphrase {
+    /// BIP-39 passphrase of the seed phrase: the passphrase itself, @path/to/file, - (stdin) or env:VAR_NAME
+    #[clap(long)]
+    pub seed_phrase_passphrase: Option<SecretArg<String>>,
+    /// ask for the BIP-39 passphrase of the seed phrase in a hidden prompt
+    #[clap(long, conflicts_with = "seed-phrase-passphrase")]
+    pub ask_seed_phrase_passphrase: bool,
+}
+
+impl CliSeedPhrasePassphrase {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(seed_phrase_passphrase) = &self.seed_phrase_passphrase {
+            args.push_front(seed_phrase_passphrase.to_string());
+            args.push_front("--seed-phrase-passphrase".to_owned());
+        }
+        if self.ask_seed_phrase_passphrase {
+            args.push_front("--ask-seed-phrase-passphrase".to_owned());
+        }
+        args
+    }
+
+    /// The passphrase, or an empty string when none is used. A passphrase for a new seed phrase
+    /// has to be typed twice.
+    pub fn passphrase(&self, new: bool) -> color_eyre::eyre::Result<String> {
+        if let Some(seed_phrase_passphrase) = &self.seed_phrase_passphrase {
+            return Ok(seed_phrase_passphrase.inner.clone());
+        }
+        if !self.ask_seed_phrase_passphrase {
+            return Ok(String::new());
+        }
+        crate::common::ensure_interactive("seed-phrase-passphrase")?;
+        let prompt = dialoguer::Password::new().with_prompt("Enter the BIP-39 passphrase");
+        if new {
+            Ok(prompt
+                .with_confirmation("Repeat the passphrase", "The passphrases don't match")
+                .interact()?)
+        } else {
+            Ok(prompt.interact()?)
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TransactionAsBase64 {
     pub inner: near_primitives::transaction::Transaction,
@@ -75,33 +308,6 @@ impl std::fmt::Display for BlockHashAsBase58 {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct AvailableRpcServerUrl {
-    pub inner: url::Url,
-}
-
-impl std::str::FromStr for AvailableRpcServerUrl {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let url: url::Url =
-            url::Url::parse(s).map_err(|err| format!("URL is not parsed: {}", err))?;
-        actix::System::new()
-            .block_on(async {
-                near_jsonrpc_client::new_client(&url.as_str())
-                    .status()
-                    .await
-            })
-            .map_err(|err| format!("AvailableRpcServerUrl: {:?}", err))?;
-        Ok(Self { inner: url })
-    }
-}
-
-impl std::fmt::Display for AvailableRpcServerUrl {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.inner.fmt(f)
-    }
-}
-
 const ONE_NEAR: u128 = 10u128.pow(24);
 
 #[derive(Debug, Clone, Default, PartialEq, PartialOrd)]
@@ -272,64 +478,153 @@ impl NearGas {
     }
 }
 
-#[derive(Debug, Clone)]
-pub enum ConnectionConfig {
-    Testnet,
-    Mainnet,
-    Betanet,
-    Custom { url: url::Url },
+/// A network from the near-cli config file; on the command line it is referred to by its name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionConfig {
+    network_config: crate::config::NetworkConfig,
+    rpc_config: crate::config::RpcConfig,
+}
+
+impl std::str::FromStr for ConnectionConfig {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let config = crate::config::Config::load().map_err(|err| err.to_string())?;
+        let network_config = config.network(s).ok_or_else(|| {
+            format!(
+                "Network <{}> is not defined in the config file. Available networks: {}",
+                s,
+                config.network_names().join(", ")
+            )
+        })?;
+        Ok(Self {
+            network_config: network_config.clone(),
+            rpc_config: config.rpc.clone(),
+        })
+    }
+}
+
+impl std::fmt::Display for ConnectionConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.network_name())
+    }
+}
+
+thread_local! {
+    /// The network given with the top-level `--network` flag or the `NEAR_NETWORK` env var
+    static PRESELECTED_NETWORK: std::cell::RefCell<Option<ConnectionConfig>> =
+        std::cell::RefCell::new(None);
 }
 
 impl ConnectionConfig {
-    pub fn rpc_url(&self) -> url::Url {
-        match self {
-            Self::Testnet => crate::consts::TESTNET_API_SERVER_URL.parse().unwrap(),
-            Self::Mainnet => crate::consts::MAINNET_API_SERVER_URL.parse().unwrap(),
-            Self::Betanet => crate::consts::BETANET_API_SERVER_URL.parse().unwrap(),
-            Self::Custom { url } => url.clone(),
-        }
+    /// Makes every following `choose_network` call return this network instead of prompting.
+    pub fn preselect_network(connection_config: Self) {
+        PRESELECTED_NETWORK.with(|preselected_network| {
+            *preselected_network.borrow_mut() = Some(connection_config)
+        });
     }
 
-    pub fn archival_rpc_url(&self) -> url::Url {
-        match self {
-            Self::Testnet => crate::consts::TESTNET_ARCHIVAL_API_SERVER_URL
-                .parse()
-                .unwrap(),
-            Self::Mainnet => crate::consts::MAINNET_ARCHIVAL_API_SERVER_URL
-                .parse()
-                .unwrap(),
-            Self::Betanet => crate::consts::BETANET_ARCHIVAL_API_SERVER_URL
-                .parse()
-                .unwrap(),
-            Self::Custom { url } => url.clone(),
+    /// The network given with `--network`
+    pub fn preselected() -> Option<Self> {
+        PRESELECTED_NETWORK.with(|preselected_network| preselected_network.borrow().clone())
+    }
+
+    pub fn choose_network(prompt: &str) -> color_eyre::eyre::Result<Self> {
+        if let Some(connection_config) = Self::preselected() {
+            return Ok(connection_config);
         }
+        let config = crate::config::Config::load()?;
+        ensure_interactive("network")?;
+        let selected_network =
+            dialoguer::Select::with_theme(&dialoguer::theme::ColorfulTheme::default())
+                .with_prompt(prompt)
+                .items(&config.network_names())
+                .default(0)
+                .interact()?;
+        Ok(Self {
+            network_config: config.networks[selected_network].clone(),
+            rpc_config: config.rpc,
+        })
     }
 
-    pub fn wallet_url(&self) -> url::Url {
-        match self {
-            Self::Testnet => crate::consts::TESTNET_WALLET_URL.parse().unwrap(),
-            Self::Mainnet => crate::consts::MAINNET_WALLET_URL.parse().unwrap(),
-            Self::Betanet => crate::consts::BETANET_WALLET_URL.parse().unwrap(),
-            Self::Custom { url } => url.clone(),
+    /// The network given with `--network`, or every network of the config file without it
+    pub fn preselected_or_all_networks() -> color_eyre::eyre::Result<Vec<Self>> {
+        if let Some(connection_config) = Self::preselected() {
+            return Ok(vec![connection_config]);
         }
+        let config = crate::config::Config::load()?;
+        Ok(config
+            .networks
+            .iter()
+            .map(|network_config| Self {
+                network_config: network_config.clone(),
+                rpc_config: config.rpc.clone(),
+            })
+            .collect())
     }
 
-    pub fn transaction_explorer(&self) -> url::Url {
-        match self {
-            Self::Testnet => crate::consts::TESTNET_TRANSACTION_URL.parse().unwrap(),
-            Self::Mainnet => crate::consts::MAINNET_TRANSACTION_URL.parse().unwrap(),
-            Self::Betanet => crate::consts::BETANET_TRANSACTION_URL.parse().unwrap(),
-            Self::Custom { url } => url.clone(),
+    pub fn network_name(&self) -> &str {
+        &self.network_config.network_name
+    }
+
+    /// The client for `rpc_url` that fails over to `fallback_rpc_urls`
+    pub fn rpc_client(&self) -> crate::rpc_client::RpcClient {
+        let mut urls = vec![self.network_config.rpc_url.clone()];
+        urls.extend(self.network_config.fallback_rpc_urls.iter().cloned());
+        crate::rpc_client::RpcClient::new(
+            urls,
+            self.network_config.rpc_headers.clone(),
+            self.rpc_config.clone(),
+        )
+    }
+
+    /// Falls back to `rpc_client` when the network has no dedicated archival node
+    pub fn archival_rpc_client(&self) -> crate::rpc_client::RpcClient {
+        match &self.network_config.archival_rpc_url {
+            Some(archival_rpc_url) => crate::rpc_client::RpcClient::new(
+                vec![archival_rpc_url.clone()],
+                self.network_config.rpc_headers.clone(),
+                self.rpc_config.clone(),
+            ),
+            None => self.rpc_client(),
         }
     }
 
+    pub fn wallet_url(&self) -> color_eyre::eyre::Result<url::Url> {
+        self.network_config.wallet_url.clone().ok_or_else(|| {
+            color_eyre::Report::msg(format!(
+                "Network <{}> has no wallet, so this command cannot be used with it. Set `wallet_url` for this network in the config file {}.",
+                self.network_name(),
+                crate::config::Config::config_path()
+                    .map(|path| path.display().to_string())
+                    .unwrap_or_default()
+            ))
+        })
+    }
+
+    pub fn transaction_explorer(&self) -> Option<url::Url> {
+        self.network_config.explorer_transaction_url.clone()
+    }
+
     pub fn dir_name(&self) -> &str {
-        match self {
-            Self::Testnet => crate::consts::DIR_NAME_TESTNET,
-            Self::Mainnet => crate::consts::DIR_NAME_MAINNET,
-            Self::Betanet => crate::consts::DIR_NAME_BETANET,
-            Self::Custom { url: _ } => crate::consts::DIR_NAME_CUSTOM,
-        }
+        &self.network_config.credentials_dir
+    }
+
+    /// `credentials_dir` resolved against the home dir
+    pub fn credentials_path(&self) -> std::path::PathBuf {
+        let mut path = dirs::home_dir().expect("Impossible to get your home dir!");
+        path.push(self.dir_name());
+        path
+    }
+
+    pub fn validator_key_path(&self) -> Option<std::path::PathBuf> {
+        self.network_config
+            .validator_key_path
+            .as_ref()
+            .map(|validator_key_path| {
+                let mut path = dirs::home_dir().expect("Impossible to get your home dir!");
+                path.push(validator_key_path);
+                path
+            })
     }
 }
 
@@ -338,7 +633,8 @@ pub fn check_account_id(
     account_id: near_primitives::types::AccountId,
 ) -> color_eyre::eyre::Result<Option<near_primitives::views::AccountView>> {
     let query_view_method_response = actix::System::new().block_on(async {
-        near_jsonrpc_client::new_client(connection_config.rpc_url().as_str())
+        connection_config
+            .rpc_client()
             .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
                 block_reference: near_primitives::types::Finality::Final.into(),
                 request: near_primitives::views::QueryRequest::ViewAccount { account_id },
@@ -376,57 +672,179 @@ pub fn is_64_len_hex(account_id: impl AsRef<str>) -> bool {
 pub struct KeyPairProperties {
     pub seed_phrase_hd_path: slip10::BIP32Path,
     pub master_seed_phrase: String,
-    pub implicit_account_id: near_primitives::types::AccountId,
+    /// `None` for secp256k1 keys: only ed25519 keys have implicit accounts
+    pub implicit_account_id: Option<near_primitives::types::AccountId>,
     pub public_key_str: String,
     pub secret_keypair_str: String,
 }
 
-pub async fn generate_keypair() -> color_eyre::eyre::Result<KeyPairProperties> {
+/// Generates a new seed phrase and derives the key pair with the default HD path.
+pub async fn generate_keypair(
+    key_type: near_crypto::KeyType,
+    seed_phrase_passphrase: &str,
+) -> color_eyre::eyre::Result<KeyPairProperties> {
     let generate_keypair: crate::commands::utils_command::generate_keypair_subcommand::CliGenerateKeypair =
         crate::commands::utils_command::generate_keypair_subcommand::CliGenerateKeypair::default();
-    let (master_seed_phrase, master_seed) =
-        if let Some(master_seed_phrase) = generate_keypair.master_seed_phrase.as_deref() {
-            (
-                master_seed_phrase.to_owned(),
-                bip39::Mnemonic::parse(master_seed_phrase)?.to_seed(""),
-            )
-        } else {
-            let mnemonic =
-                bip39::Mnemonic::generate(generate_keypair.new_master_seed_phrase_words_count)?;
-            let master_seed_phrase = mnemonic.word_iter().collect::<Vec<&str>>().join(" ");
-            (master_seed_phrase, mnemonic.to_seed(""))
-        };
+    let mnemonic = bip39::Mnemonic::generate(generate_keypair.new_master_seed_phrase_words_count)?;
+    get_key_pair_properties_from_seed_phrase(
+        mnemonic.word_iter().collect::<Vec<&str>>().join(" "),
+        generate_keypair.seed_phrase_hd_path,
+        key_type,
+        seed_phrase_passphrase,
+    )
+}
 
-    let derived_private_key = slip10::derive_key_from_path(
+/// Derives the key pair from the seed phrase; `seed_phrase_passphrase` is the optional BIP-39
+/// passphrase, empty when it is not used.
+pub fn get_key_pair_properties_from_seed_phrase(
+    master_seed_phrase: String,
+    seed_phrase_hd_path: slip10::BIP32Path,
+    key_type: near_crypto::KeyType,
+    seed_phrase_passphrase: &str,
+) -> color_eyre::eyre::Result<KeyPairProperties> {
+    let master_seed = bip39::Mnemonic::parse(&master_seed_phrase)?.to_seed(seed_phrase_passphrase);
+    key_pair_properties_from_master_seed(
         &master_seed,
-        slip10::Curve::Ed25519,
-        &generate_keypair.seed_phrase_hd_path,
+        master_seed_phrase,
+        seed_phrase_hd_path,
+        key_type,
     )
-    .map_err(|err| {
-        color_eyre::Report::msg(format!(
-            "Failed to derive a key from the master key: {}",
-            err
-        ))
-    })?;
+}
 
-    let secret_keypair = {
-        let secret = ed25519_dalek::SecretKey::from_bytes(&derived_private_key.key)?;
-        let public = ed25519_dalek::PublicKey::from(&secret);
-        ed25519_dalek::Keypair { secret, public }
+/// Derives the key pairs of all the HD paths from the seed phrase.
+pub fn get_key_pairs_properties_from_seed_phrase(
+    master_seed_phrase: String,
+    seed_phrase_hd_paths: Vec<slip10::BIP32Path>,
+    key_type: near_crypto::KeyType,
+    seed_phrase_passphrase: &str,
+) -> color_eyre::eyre::Result<Vec<KeyPairProperties>> {
+    let master_seed = bip39::Mnemonic::parse(&master_seed_phrase)?.to_seed(seed_phrase_passphrase);
+    seed_phrase_hd_paths
+        .into_iter()
+        .map(|seed_phrase_hd_path| {
+            key_pair_properties_from_master_seed(
+                &master_seed,
+                master_seed_phrase.clone(),
+                seed_phrase_hd_path,
+                key_type,
+            )
+        })
+        .collect()
+}
+
+/// The HD paths of `count` keys: `seed_phrase_hd_path` and the paths following it in the last index,
+/// e.g. m/44'/397'/0', m/44'/397'/1', m/44'/397'/2'
+pub fn consecutive_seed_phrase_hd_paths(
+    seed_phrase_hd_path: &slip10::BIP32Path,
+    count: u32,
+) -> color_eyre::eyre::Result<Vec<slip10::BIP32Path>> {
+    const HARDENED: u32 = 1 << 31;
+    let mut indexes = (0..seed_phrase_hd_path.depth())
+        .map(|depth| *seed_phrase_hd_path.index(depth).unwrap())
+        .collect::<Vec<u32>>();
+    let last_index = indexes.pop().ok_or_else(|| {
+        color_eyre::Report::msg("The seed phrase HD path has no index to walk".to_owned())
+    })?;
+    let (first_index, hardened) = if last_index >= HARDENED {
+        (last_index - HARDENED, true)
+    } else {
+        (last_index, false)
     };
+    let parent = indexes
+        .iter()
+        .map(|&index| {
+            if index >= HARDENED {
+                format!("/{}'", index - HARDENED)
+            } else {
+                format!("/{}", index)
+            }
+        })
+        .collect::<String>();
+    (0..count)
+        .map(|offset| {
+            let index = first_index
+                .checked_add(offset)
+                .filter(|index| *index < HARDENED)
+                .ok_or_else(|| {
+                    color_eyre::Report::msg(format!(
+                        "The HD path index {} + {} is out of range",
+                        first_index, offset
+                    ))
+                })?;
+            let seed_phrase_hd_path =
+                format!("m{}/{}{}", parent, index, if hardened { "'" } else { "" });
+            <slip10::BIP32Path as std::str::FromStr>::from_str(&seed_phrase_hd_path).map_err(
+                |err| {
+                    color_eyre::Report::msg(format!(
+                        "Failed to build the HD path {}: {:?}",
+                        seed_phrase_hd_path, err
+                    ))
+                },
+            )
+        })
+        .collect()
+}
 
-    let implicit_account_id =
-        near_primitives::types::AccountId::try_from(hex::encode(&secret_keypair.public))?;
-    let public_key_str = format!(
-        "ed25519:{}",
-        bs58::encode(&secret_keypair.public).into_string()
-    );
-    let secret_keypair_str = format!(
-        "ed25519:{}",
-        bs58::encode(secret_keypair.to_bytes()).into_string()
-    );
+fn key_pair_properties_from_master_seed(
+    master_seed: &[u8],
+    master_seed_phrase: String,
+    seed_phrase_hd_path: slip10::BIP32Path,
+    key_type: near_crypto::KeyType,
+) -> color_eyre::eyre::Result<KeyPairProperties> {
+    let (implicit_account_id, public_key_str, secret_keypair_str) = match key_type {
+        near_crypto::KeyType::ED25519 => {
+            let derived_private_key = slip10::derive_key_from_path(
+                master_seed,
+                slip10::Curve::Ed25519,
+                &seed_phrase_hd_path,
+            )
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to derive a key from the master key: {}",
+                    err
+                ))
+            })?;
+
+            let secret_keypair = {
+                let secret = ed25519_dalek::SecretKey::from_bytes(&derived_private_key.key)?;
+                let public = ed25519_dalek::PublicKey::from(&secret);
+                ed25519_dalek::Keypair { secret, public }
+            };
+
+            let implicit_account_id =
+                near_primitives::types::AccountId::try_from(hex::encode(&secret_keypair.public))?;
+            let public_key_str = format!(
+                "ed25519:{}",
+                bs58::encode(&secret_keypair.public).into_string()
+            );
+            let secret_keypair_str = format!(
+                "ed25519:{}",
+                bs58::encode(secret_keypair.to_bytes()).into_string()
+            );
+            (
+                Some(implicit_account_id),
+                public_key_str,
+                secret_keypair_str,
+            )
+        }
+        near_crypto::KeyType::SECP256K1 => {
+            let derived_private_key =
+                derive_secp256k1_key_from_path(master_seed, &seed_phrase_hd_path)?;
+            let secret_key = format!(
+                "secp256k1:{}",
+                bs58::encode(&derived_private_key).into_string()
+            )
+            .parse::<near_crypto::SecretKey>()
+            .map_err(|err| color_eyre::Report::msg(format!("Invalid secp256k1 key: {}", err)))?;
+            (
+                None,
+                secret_key.public_key().to_string(),
+                secret_key.to_string(),
+            )
+        }
+    };
     let key_pair_properties: KeyPairProperties = KeyPairProperties {
-        seed_phrase_hd_path: generate_keypair.seed_phrase_hd_path,
+        seed_phrase_hd_path,
         master_seed_phrase,
         implicit_account_id,
         public_key_str,
@@ -435,7 +853,106 @@ pub async fn generate_keypair() -> color_eyre::eyre::Result<KeyPairProperties> {
     Ok(key_pair_properties)
 }
 
+/// The order n of the secp256k1 curve
+const SECP256K1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> [u8; 64] {
+    use hmac::{Mac, NewMac};
+    let mut mac = hmac::Hmac::<sha2::Sha512>::new_varkey(key).expect("HMAC takes keys of any size");
+    for part in data {
+        mac.update(part);
+    }
+    let mut output = [0u8; 64];
+    output.copy_from_slice(&mac.finalize().into_bytes());
+    output
+}
+
+/// (a + b) mod n for big-endian a, b < n
+fn add_mod_secp256k1_order(a: &[u8], b: &[u8]) -> [u8; 32] {
+    let mut sum = [0u8; 32];
+    let mut carry = 0u16;
+    for i in (0..32).rev() {
+        let digit = a[i] as u16 + b[i] as u16 + carry;
+        sum[i] = digit as u8;
+        carry = digit >> 8;
+    }
+    // a + b < 2n, so subtracting n once is enough; with a carry the borrow cancels it out
+    if carry != 0 || sum >= SECP256K1_ORDER {
+        let mut borrow = 0i16;
+        for i in (0..32).rev() {
+            let digit = sum[i] as i16 - SECP256K1_ORDER[i] as i16 - borrow;
+            borrow = if digit < 0 { 1 } else { 0 };
+            sum[i] = (digit + 256 * borrow) as u8;
+        }
+    }
+    sum
+}
+
+/// SLIP-10 derivation of a secp256k1 private key (the same as BIP-32 for hardened indexes);
+/// the slip10 crate derives ed25519 keys only.
+fn derive_secp256k1_key_from_path(
+    master_seed: &[u8],
+    seed_phrase_hd_path: &slip10::BIP32Path,
+) -> color_eyre::eyre::Result<[u8; 32]> {
+    const HARDENED: u32 = 1 << 31;
+    let is_valid = |key: &[u8]| key < &SECP256K1_ORDER[..] && key.iter().any(|byte| *byte != 0);
+
+    let mut i = hmac_sha512(b"Bitcoin seed", &[master_seed]);
+    while !is_valid(&i[..32]) {
+        i = hmac_sha512(b"Bitcoin seed", &[&i[..]]);
+    }
+    let (mut key, mut chain_code) = ([0u8; 32], [0u8; 32]);
+    key.copy_from_slice(&i[..32]);
+    chain_code.copy_from_slice(&i[32..]);
+
+    for depth in 0..seed_phrase_hd_path.depth() {
+        let index = *seed_phrase_hd_path.index(depth).unwrap();
+        if index < HARDENED {
+            return Err(color_eyre::Report::msg(
+                "secp256k1 keys are derived only with hardened HD path indexes, such as m/44'/397'/0'",
+            ));
+        }
+        let index = index.to_be_bytes();
+        i = hmac_sha512(&chain_code, &[&[0], &key, &index]);
+        loop {
+            if &i[..32] < &SECP256K1_ORDER[..] {
+                let child_key = add_mod_secp256k1_order(&i[..32], &key);
+                if is_valid(&child_key) {
+                    key = child_key;
+                    chain_code.copy_from_slice(&i[32..]);
+                    break;
+                }
+            }
+            i = hmac_sha512(&chain_code, &[&[1], &i[32..], &index]);
+        }
+    }
+    Ok(key)
+}
+
+/// Asks which type of key to generate.
+pub fn input_key_type() -> color_eyre::eyre::Result<near_crypto::KeyType> {
+    let key_types = [
+        near_crypto::KeyType::ED25519,
+        near_crypto::KeyType::SECP256K1,
+    ];
+    crate::common::ensure_interactive("key-type")?;
+    let selection = dialoguer::Select::with_theme(&dialoguer::theme::ColorfulTheme::default())
+        .with_prompt("Select the key type")
+        .items(&["ed25519", "secp256k1"])
+        .default(0)
+        .interact()
+        .unwrap();
+    Ok(key_types[selection])
+}
+
 pub fn print_transaction(transaction: near_primitives::transaction::Transaction) {
+    add_json_output(
+        "transaction",
+        serde_json::to_value(&transaction).unwrap_or_default(),
+    );
     println!("{:<13} {}", "signer_id:", &transaction.signer_id);
     println!("{:<13} {}", "public_key:", &transaction.public_key);
     println!("{:<13} {}", "nonce:", &transaction.nonce);
@@ -794,28 +1311,73 @@ pub async fn print_transaction_error(
     }
 }
 
+/// The return value of a function call is base64-encoded; it is shown as JSON when it is JSON
+/// (most contracts return JSON), as a string when it is UTF-8 text and as base64 otherwise.
+pub fn decode_return_value(value: &str) -> serde_json::Value {
+    match near_primitives::serialize::from_base64(value) {
+        Ok(bytes) if bytes.is_empty() => serde_json::Value::Null,
+        Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_else(|_| {
+            String::from_utf8(bytes)
+                .map(serde_json::Value::String)
+                .unwrap_or_else(|_| serde_json::Value::String(value.to_owned()))
+        }),
+        Err(_) => serde_json::Value::String(value.to_owned()),
+    }
+}
+
 pub async fn print_transaction_status(
     transaction_info: near_primitives::views::FinalExecutionOutcomeView,
     network_connection_config: Option<crate::common::ConnectionConfig>,
 ) {
-    match transaction_info.status {
+    match transaction_info.status.clone() {
         near_primitives::views::FinalExecutionStatus::NotStarted
         | near_primitives::views::FinalExecutionStatus::Started => unreachable!(),
         near_primitives::views::FinalExecutionStatus::Failure(tx_execution_error) => {
+            add_json_output("status", serde_json::json!("failure"));
+            add_json_output(
+                "error",
+                serde_json::to_value(&tx_execution_error).unwrap_or_default(),
+            );
             print_transaction_error(tx_execution_error).await
         }
-        near_primitives::views::FinalExecutionStatus::SuccessValue(_) => {
+        near_primitives::views::FinalExecutionStatus::SuccessValue(value) => {
+            add_json_output("status", serde_json::json!("success"));
+            add_json_output("return_value", decode_return_value(&value));
             print_value_successful_transaction(transaction_info.clone()).await
         }
     };
-    let transaction_explorer: url::Url = match network_connection_config {
+    let transaction_explorer: Option<url::Url> = match network_connection_config {
         Some(connection_config) => connection_config.transaction_explorer(),
         None => unreachable!("Error"),
     };
-    println!("Transaction ID: {id}.\nTo see the transaction in the transaction explorer, please open this url in your browser:\n{path}{id}\n",
-        id=transaction_info.transaction_outcome.id,
-        path=transaction_explorer
+    add_json_output(
+        "transaction_hash",
+        serde_json::json!(transaction_info.transaction_outcome.id.to_string()),
     );
+    add_json_output(
+        "explorer_url",
+        match &transaction_explorer {
+            Some(transaction_explorer) => serde_json::json!(format!(
+                "{}{}",
+                transaction_explorer, transaction_info.transaction_outcome.id
+            )),
+            None => serde_json::Value::Null,
+        },
+    );
+    add_json_output(
+        "outcome",
+        serde_json::to_value(&transaction_info).unwrap_or_default(),
+    );
+    match transaction_explorer {
+        Some(transaction_explorer) => println!("Transaction ID: {id}.\nTo see the transaction in the transaction explorer, please open this url in your browser:\n{path}{id}\n",
+            id=transaction_info.transaction_outcome.id,
+            path=transaction_explorer
+        ),
+        None => println!(
+            "Transaction ID: {}.\nThis network has no transaction explorer.\n",
+            transaction_info.transaction_outcome.id
+        ),
+    };
 }
 
 pub async fn save_access_key_to_keychain(
@@ -823,40 +1385,64 @@ pub async fn save_access_key_to_keychain(
     key_pair_properties: crate::common::KeyPairProperties,
     account_id: &str,
 ) -> crate::CliResult {
-    let buf = format!(
-        "{}",
-        serde_json::json!({
-            "master_seed_phrase": key_pair_properties.master_seed_phrase,
-            "seed_phrase_hd_path": key_pair_properties.seed_phrase_hd_path.to_string(),
-            "account_id": account_id,
-            "public_key": key_pair_properties.public_key_str,
-            "private_key": key_pair_properties.secret_keypair_str,
-        })
-    );
+    let key_file = serde_json::json!({
+        "master_seed_phrase": key_pair_properties.master_seed_phrase,
+        "seed_phrase_hd_path": key_pair_properties.seed_phrase_hd_path.to_string(),
+        "account_id": account_id,
+        "public_key": key_pair_properties.public_key_str,
+        "private_key": key_pair_properties.secret_keypair_str,
+    });
+    save_key_file_to_keychain(
+        network_connection_config,
+        &key_file,
+        account_id,
+        &key_pair_properties.public_key_str,
+    )
+}
+
+/// Saves the key file as `<credentials_dir>/<account_id>/<public_key>.json` and, unless it exists,
+/// as `<credentials_dir>/<account_id>.json` in the format the JavaScript near-cli reads.
+pub fn save_key_file_to_keychain(
+    network_connection_config: Option<crate::common::ConnectionConfig>,
+    key_file: &serde_json::Value,
+    account_id: &str,
+    public_key_str: &str,
+) -> crate::CliResult {
     let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
     let dir_name = match &network_connection_config {
         Some(connection_config) => connection_config.dir_name(),
         None => crate::consts::DIR_NAME_KEY_CHAIN,
     };
-    let file_with_key_name: std::path::PathBuf = format!(
-        "{}.json",
-        key_pair_properties.public_key_str.replace(":", "_")
-    )
-    .into();
+    let file_with_key_name: std::path::PathBuf =
+        format!("{}.json", public_key_str.replace(":", "_")).into();
     let mut path_with_key_name = std::path::PathBuf::from(&home_dir);
     path_with_key_name.push(dir_name);
     path_with_key_name.push(account_id);
-    std::fs::create_dir_all(&path_with_key_name)?;
+    crate::keychain::create_private_dir(&path_with_key_name)?;
     path_with_key_name.push(file_with_key_name);
-    std::fs::File::create(&path_with_key_name)
-        .map_err(|err| color_eyre::Report::msg(format!("Failed to create file: {:?}", err)))?
-        .write(buf.as_bytes())
-        .map_err(|err| color_eyre::Report::msg(format!("Failed to write to file: {:?}", err)))?;
+    crate::keychain::write_key_file(&path_with_key_name, key_file)?;
+    add_json_output(
+        "access_key",
+        serde_json::json!({
+            "account_id": account_id,
+            "public_key": public_key_str,
+            "file": path_with_key_name,
+        }),
+    );
     println!(
         "The data for the access key is saved in a file {}",
         &path_with_key_name.display()
     );
 
+    // near-api-js cannot use a key without its private key, e.g. a Ledger key
+    if key_file.get("private_key").is_none() {
+        return Ok(());
+    }
+    // near-api-js cannot decrypt, and a plaintext copy would defeat the encryption
+    if crate::config::Config::load()?.keychain.encrypt {
+        println!("The key is not saved for near-api-js, since the keychain is encrypted");
+        return Ok(());
+    }
     let file_with_account_name: std::path::PathBuf = format!("{}.json", account_id).into();
     let mut path_with_account_name = std::path::PathBuf::from(&home_dir);
     path_with_account_name.push(dir_name);
@@ -867,12 +1453,12 @@ pub async fn save_access_key_to_keychain(
             &path_with_account_name.display()
         );
     } else {
-        std::fs::File::create(&path_with_account_name)
-            .map_err(|err| color_eyre::Report::msg(format!("Failed to create file: {:?}", err)))?
-            .write(buf.as_bytes())
-            .map_err(|err| {
-                color_eyre::Report::msg(format!("Failed to write to file: {:?}", err))
-            })?;
+        crate::keychain::write_private_file(
+            &path_with_account_name,
+            crate::keychain::js_compatible_key_file(key_file)
+                .to_string()
+                .as_bytes(),
+        )?;
         println!(
             "The data for the access key is saved in a file {}",
             &path_with_account_name.display()
@@ -886,6 +1472,214 @@ mod tests {
     use super::*;
     use std::str::FromStr;
 
+    #[test]
+    fn secret_arg_from_env_and_file() {
+        std::env::set_var("NEAR_CLI_TEST_SECRET", " secret value\n");
+        let secret = SecretArg::<String>::from_str("env:NEAR_CLI_TEST_SECRET").unwrap();
+        assert_eq!(secret.inner, "secret value");
+        assert_eq!(secret.to_string(), "env:NEAR_CLI_TEST_SECRET");
+
+        let path = std::env::temp_dir().join("near-cli-test-secret.txt");
+        std::fs::write(&path, "file secret\n").unwrap();
+        let secret = SecretArg::<String>::from_str(&format!("@{}", path.display())).unwrap();
+        assert_eq!(secret.inner, "file secret");
+        assert_eq!(secret.to_string(), format!("@{}", path.display()));
+
+        let secret = SecretArg::<String>::from_str("plain secret").unwrap();
+        assert_eq!(secret.to_string(), SECRET_PLACEHOLDER);
+        assert_eq!(format!("{:?}", secret), "SecretArg(<secret>)");
+        assert!(SecretArg::<String>::from_str("env:NEAR_CLI_TEST_UNSET_SECRET").is_err());
+        assert!(SecretArg::<String>::from_str(SECRET_PLACEHOLDER).is_err());
+    }
+
+    #[test]
+    fn slip10_ed25519_test_vector() {
+        // SLIP-0010 test vector 1 for ed25519, chain m/0'/1'
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let derived_private_key = slip10::derive_key_from_path(
+            &seed,
+            slip10::Curve::Ed25519,
+            &slip10::BIP32Path::from_str("m/0'/1'").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            hex::encode(&derived_private_key.key),
+            "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2"
+        );
+        let secret = ed25519_dalek::SecretKey::from_bytes(&derived_private_key.key).unwrap();
+        assert_eq!(
+            hex::encode(ed25519_dalek::PublicKey::from(&secret)),
+            "1932a5270f335bed617d5b935c80aedb1a35bd9fc1e31acafd5372c30f5c1187"
+        );
+    }
+
+    #[test]
+    fn secp256k1_key_derivation() {
+        // BIP-32 test vector 1, chain m/0'
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        assert_eq!(
+            hex::encode(
+                derive_secp256k1_key_from_path(
+                    &seed,
+                    &slip10::BIP32Path::from_str("m/0'").unwrap()
+                )
+                .unwrap()
+            ),
+            "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea"
+        );
+        assert!(derive_secp256k1_key_from_path(
+            &seed,
+            &slip10::BIP32Path::from_str("m/0'/1").unwrap()
+        )
+        .is_err());
+
+        let seed_phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        for (passphrase, public_key, secret_key) in [
+            (
+                "",
+                "secp256k1:3rcYVfQ8TW4n79fsoQhrUHgXYV8tnn2E8Py7fjA5Q3iAgokHrjayyxqbH3urdxWTvfH5rJEK2zqAqgm7z5vSDHp2",
+                "secp256k1:139syRJBYGSnwRM75LB2iBH7XPaRQs1TtubQPaBLfnNR",
+            ),
+            (
+                "TREZOR",
+                "secp256k1:5j9KXmqUrkdtADsEH2cL8jniardNqmUCCZGHixZCQgYQjtq1Bj1vpksUDZ5NfL92GMvvgh2rPeTUMnguKmpMog9G",
+                "secp256k1:HrcARuJ1W9PfrQeppM4LbQELaTg58VvbPDp23hKPAY7E",
+            ),
+        ]
+        .iter()
+        {
+            let key_pair_properties = get_key_pair_properties_from_seed_phrase(
+                seed_phrase.to_owned(),
+                slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap(),
+                near_crypto::KeyType::SECP256K1,
+                passphrase,
+            )
+            .unwrap();
+            assert_eq!(key_pair_properties.public_key_str, *public_key);
+            assert_eq!(key_pair_properties.secret_keypair_str, *secret_key);
+            assert!(key_pair_properties.implicit_account_id.is_none());
+        }
+    }
+
+    #[test]
+    fn derive_consecutive_keys_from_seed_phrase() {
+        let seed_phrase_hd_paths = consecutive_seed_phrase_hd_paths(
+            &slip10::BIP32Path::from_str("m/44'/397'/5'").unwrap(),
+            3,
+        )
+        .unwrap();
+        assert_eq!(
+            seed_phrase_hd_paths
+                .iter()
+                .map(|seed_phrase_hd_path| seed_phrase_hd_path.to_string())
+                .collect::<Vec<_>>(),
+            ["m/44'/397'/5'", "m/44'/397'/6'", "m/44'/397'/7'"]
+        );
+        assert!(consecutive_seed_phrase_hd_paths(
+            &slip10::BIP32Path::from_str("m/44'/397'/2147483647'").unwrap(),
+            2
+        )
+        .is_err());
+
+        let seed_phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let key_pairs = get_key_pairs_properties_from_seed_phrase(
+            seed_phrase.to_owned(),
+            consecutive_seed_phrase_hd_paths(
+                &slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap(),
+                3,
+            )
+            .unwrap(),
+            near_crypto::KeyType::ED25519,
+            "",
+        )
+        .unwrap();
+        assert_eq!(
+            key_pairs[0].public_key_str,
+            "ed25519:6j4b6zUaty6fD1awqcGCCU9JYGCWYUgdJhQrzfZhqE25"
+        );
+        for key_pair in key_pairs.iter() {
+            let single_key_pair = get_key_pair_properties_from_seed_phrase(
+                seed_phrase.to_owned(),
+                key_pair.seed_phrase_hd_path.clone(),
+                near_crypto::KeyType::ED25519,
+                "",
+            )
+            .unwrap();
+            assert_eq!(
+                single_key_pair.secret_keypair_str,
+                key_pair.secret_keypair_str
+            );
+        }
+        assert_ne!(key_pairs[1].public_key_str, key_pairs[2].public_key_str);
+    }
+
+    #[test]
+    fn key_pair_from_seed_phrase_with_passphrase() {
+        // Seeds of the BIP-39 reference vectors; the keys match the ones other NEAR wallets
+        // derive with m/44'/397'/0'
+        let abandon_about = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let legal_winner =
+            "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        for (seed_phrase, passphrase, seed_prefix, public_key, secret_key) in [
+            (
+                abandon_about,
+                "",
+                "5eb00bbddcf069084889a8ab91555681",
+                "ed25519:6j4b6zUaty6fD1awqcGCCU9JYGCWYUgdJhQrzfZhqE25",
+                "ed25519:F1kPR175szkGxEL52A9H6Z5ocS2BtaipprK2Hiob9DjGzHTkScrBb1yt44baXPZ3LxyHcsTPdBjHmn6zx147txH",
+            ),
+            (
+                abandon_about,
+                "TREZOR",
+                "c55257c360c07c72029aebc1b53c05ed",
+                "ed25519:2G9P9453x98uF4sPSs4BbFzy6iMg8DXyGVKsQwpCpnL5",
+                "ed25519:3mJB9Bc3EMk1Few9WttRYCyX5E4HtruTi8T6mH1AqVR5g6fg8i2DqRptdKXLF1KunzM65q28vk6CscX45ST3mSPF",
+            ),
+            (
+                legal_winner,
+                "TREZOR",
+                "2e8905819b8723fe2c1d161860e5ee18",
+                "ed25519:C6ESmWWoR6qcvbrRBybsyh7o1utN4jzb14YvdkPzUHXx",
+                "ed25519:2suC4K4dAVpejj3221ZE8xYHegSp2zwMTMruF5XBwNHv92mSW6vR6BVj2mHV3saPhgQ71jB1Z3YqXGE3UdfVjYtJ",
+            ),
+        ]
+        .iter()
+        {
+            let seed = bip39::Mnemonic::parse(*seed_phrase)
+                .unwrap()
+                .to_seed(*passphrase);
+            assert!(hex::encode(&seed[..]).starts_with(seed_prefix));
+            let key_pair_properties = get_key_pair_properties_from_seed_phrase(
+                seed_phrase.to_string(),
+                slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap(),
+                near_crypto::KeyType::ED25519,
+                passphrase,
+            )
+            .unwrap();
+            assert_eq!(key_pair_properties.public_key_str, *public_key);
+            assert_eq!(key_pair_properties.secret_keypair_str, *secret_key);
+        }
+    }
+
+    #[test]
+    fn decode_return_value_json_text_and_bytes() {
+        assert_eq!(
+            decode_return_value(&near_primitives::serialize::to_base64(
+                b"{\"total\":\"10\"}"
+            )),
+            serde_json::json!({"total": "10"})
+        );
+        assert_eq!(
+            decode_return_value(&near_primitives::serialize::to_base64(b"hello")),
+            serde_json::json!("hello")
+        );
+        assert_eq!(
+            decode_return_value(&near_primitives::serialize::to_base64(&[0xff, 0xfe])),
+            serde_json::json!("//4=")
+        );
+        assert_eq!(decode_return_value(""), serde_json::Value::Null);
+    }
+
     #[test]
     fn near_balance_from_str_currency_near() {
         assert_eq!(
@@ -0,0 +1,357 @@
+use std::io::Write;
+
+/// The contents of the near-cli config file (`~/.config/near-cli/config.toml` on Linux).
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub rpc: RpcConfig,
+    #[serde(default)]
+    pub keychain: KeychainConfig,
+    pub networks: Vec<NetworkConfig>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct KeychainConfig {
+    /// Encrypt newly saved key files with a passphrase, see [`crate::keychain`]
+    pub encrypt: bool,
+}
+
+/// How persistently failed JSON-RPC requests are repeated, see [`crate::rpc_client::RpcClient`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct RpcConfig {
+    /// Retries per endpoint before moving on to the next fallback endpoint
+    pub retries: u32,
+    /// The delay before the first retry; it doubles with every following retry
+    pub initial_backoff_ms: u64,
+    pub request_timeout_secs: u64,
+}
+
+impl Default for RpcConfig {
+    fn default() -> Self {
+        Self {
+            retries: 2,
+            initial_backoff_ms: 500,
+            request_timeout_secs: 60,
+        }
+    }
+}
+
+/// A named NEAR network; the order of the networks in the config file is kept in the prompts.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct NetworkConfig {
+    pub network_name: String,
+    pub rpc_url: url::Url,
+    /// Used in order when `rpc_url` keeps failing
+    #[serde(default)]
+    pub fallback_rpc_urls: Vec<url::Url>,
+    /// Falls back to `rpc_url` when the network has no dedicated archival node
+    pub archival_rpc_url: Option<url::Url>,
+    /// Local networks have neither a wallet nor an explorer
+    pub wallet_url: Option<url::Url>,
+    pub explorer_transaction_url: Option<url::Url>,
+    /// The credentials directory, either absolute or relative to the home dir
+    pub credentials_dir: String,
+    /// The `validator_key.json` of a local node, used by keychain signing for the node's own
+    /// account (`test.near`); either absolute or relative to the home dir
+    pub validator_key_path: Option<String>,
+    /// Sent with every JSON-RPC request, e.g. the `x-api-key` of a commercial RPC provider;
+    /// `${VAR_NAME}` in a value is replaced with that environment variable
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub rpc_headers: std::collections::BTreeMap<String, String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            rpc: RpcConfig::default(),
+            keychain: KeychainConfig::default(),
+            networks: vec![
+                NetworkConfig {
+                    network_name: "testnet".to_owned(),
+                    rpc_url: crate::consts::TESTNET_API_SERVER_URL.parse().unwrap(),
+                    fallback_rpc_urls: vec![],
+                    archival_rpc_url: Some(
+                        crate::consts::TESTNET_ARCHIVAL_API_SERVER_URL
+                            .parse()
+                            .unwrap(),
+                    ),
+                    wallet_url: Some(crate::consts::TESTNET_WALLET_URL.parse().unwrap()),
+                    explorer_transaction_url: Some(
+                        crate::consts::TESTNET_TRANSACTION_URL.parse().unwrap(),
+                    ),
+                    credentials_dir: crate::consts::DIR_NAME_TESTNET.to_owned(),
+                    validator_key_path: None,
+                    rpc_headers: Default::default(),
+                },
+                NetworkConfig {
+                    network_name: "mainnet".to_owned(),
+                    rpc_url: crate::consts::MAINNET_API_SERVER_URL.parse().unwrap(),
+                    fallback_rpc_urls: vec![],
+                    archival_rpc_url: Some(
+                        crate::consts::MAINNET_ARCHIVAL_API_SERVER_URL
+                            .parse()
+                            .unwrap(),
+                    ),
+                    wallet_url: Some(crate::consts::MAINNET_WALLET_URL.parse().unwrap()),
+                    explorer_transaction_url: Some(
+                        crate::consts::MAINNET_TRANSACTION_URL.parse().unwrap(),
+                    ),
+                    credentials_dir: crate::consts::DIR_NAME_MAINNET.to_owned(),
+                    validator_key_path: None,
+                    rpc_headers: Default::default(),
+                },
+                NetworkConfig {
+                    network_name: "localnet".to_owned(),
+                    rpc_url: crate::consts::LOCALNET_API_SERVER_URL.parse().unwrap(),
+                    fallback_rpc_urls: vec![],
+                    archival_rpc_url: None,
+                    wallet_url: None,
+                    explorer_transaction_url: None,
+                    credentials_dir: crate::consts::DIR_NAME_LOCALNET.to_owned(),
+                    validator_key_path: Some(crate::consts::LOCALNET_VALIDATOR_KEY_PATH.to_owned()),
+                    rpc_headers: Default::default(),
+                },
+            ],
+        }
+    }
+}
+
+impl Config {
+    pub fn config_path() -> color_eyre::eyre::Result<std::path::PathBuf> {
+        let mut path = dirs::config_dir()
+            .ok_or_else(|| color_eyre::Report::msg("Impossible to get your config dir!"))?;
+        path.push(crate::consts::DIR_NAME_CONFIG);
+        path.push(crate::consts::CONFIG_FILE_NAME);
+        Ok(path)
+    }
+
+    /// Reads the config file. On the first run the file does not exist yet, so it is created
+    /// with the default networks to give users a template to edit.
+    pub fn load() -> color_eyre::eyre::Result<Self> {
+        let path = Self::config_path()?;
+        if !path.exists() {
+            let config = Self::default();
+            // A read-only home dir must not prevent using the default networks
+            let _ = config.write_to_file(&path);
+            return Ok(config);
+        }
+        let config_toml = std::fs::read_to_string(&path).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to read the config file {}: {}",
+                path.display(),
+                err
+            ))
+        })?;
+        Self::from_toml(&config_toml).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to parse the config file {}: {}",
+                path.display(),
+                err
+            ))
+        })
+    }
+
+    pub fn from_toml(config_toml: &str) -> Result<Self, String> {
+        let config: Self = toml::from_str(config_toml).map_err(|err| err.to_string())?;
+        if config.networks.is_empty() {
+            return Err("at least one network must be defined".to_string());
+        }
+        for (index, network_config) in config.networks.iter().enumerate() {
+            if config.networks[..index]
+                .iter()
+                .any(|network| network.network_name == network_config.network_name)
+            {
+                return Err(format!(
+                    "network <{}> is defined more than once",
+                    network_config.network_name
+                ));
+            }
+        }
+        Ok(config)
+    }
+
+    fn write_to_file(&self, path: &std::path::Path) -> crate::CliResult {
+        if let Some(config_dir) = path.parent() {
+            std::fs::create_dir_all(config_dir)?;
+        }
+        std::fs::File::create(path)?.write_all(toml::to_string(self)?.as_bytes())?;
+        Ok(())
+    }
+
+    pub fn network(&self, network_name: &str) -> Option<&NetworkConfig> {
+        self.networks
+            .iter()
+            .find(|network_config| network_config.network_name == network_name)
+    }
+
+    pub fn network_names(&self) -> Vec<&str> {
+        self.networks
+            .iter()
+            .map(|network_config| network_config.network_name.as_str())
+            .collect()
+    }
+}
+
+/// Replaces every `${VAR_NAME}` in the value with the environment variable, so that secrets
+/// such as RPC API keys do not have to be stored in the config file.
+pub fn expand_env_vars(value: &str) -> Result<String, String> {
+    let mut expanded = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        expanded.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| format!("unclosed `${{` in <{}>", value))?;
+        let var_name = &rest[start + 2..start + end];
+        let var_value = std::env::var(var_name)
+            .map_err(|_| format!("environment variable {} is not set", var_name))?;
+        expanded.push_str(&var_value);
+        rest = &rest[start + end + 1..];
+    }
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_default_round_trip() {
+        let config = Config::default();
+        let config_toml = toml::to_string(&config).unwrap();
+        assert_eq!(Config::from_toml(&config_toml).unwrap(), config);
+        assert_eq!(
+            config.network_names(),
+            vec!["testnet", "mainnet", "localnet"]
+        );
+    }
+
+    #[test]
+    fn config_custom_network() {
+        let config = Config::from_toml(
+            r#"
+            [[networks]]
+            network_name = "shardnet"
+            rpc_url = "https://rpc.shardnet.near.org"
+            wallet_url = "https://wallet.shardnet.near.org"
+            explorer_transaction_url = "https://explorer.shardnet.near.org/transactions/"
+            credentials_dir = ".near-credentials/shardnet/"
+            "#,
+        )
+        .unwrap();
+        let shardnet = config.network("shardnet").unwrap();
+        assert_eq!(shardnet.rpc_url.as_str(), "https://rpc.shardnet.near.org/");
+        assert_eq!(shardnet.archival_rpc_url, None);
+        assert_eq!(shardnet.validator_key_path, None);
+        assert!(shardnet.fallback_rpc_urls.is_empty());
+        assert_eq!(config.rpc, RpcConfig::default());
+        assert!(!config.keychain.encrypt);
+        assert!(config.network("testnet").is_none());
+    }
+
+    #[test]
+    fn config_localnet_without_wallet() {
+        let config = Config::from_toml(
+            r#"
+            [[networks]]
+            network_name = "localnet"
+            rpc_url = "http://127.0.0.1:3030"
+            credentials_dir = ".near-credentials/localnet/"
+            validator_key_path = ".near/validator_key.json"
+            "#,
+        )
+        .unwrap();
+        let localnet = config.network("localnet").unwrap();
+        assert_eq!(localnet.wallet_url, None);
+        assert_eq!(localnet.explorer_transaction_url, None);
+        assert_eq!(
+            localnet.validator_key_path.as_deref(),
+            Some(".near/validator_key.json")
+        );
+    }
+
+    #[test]
+    fn config_rpc_failover() {
+        let config = Config::from_toml(
+            r#"
+            [rpc]
+            retries = 5
+
+            [[networks]]
+            network_name = "testnet"
+            rpc_url = "https://rpc.testnet.near.org"
+            fallback_rpc_urls = ["https://rpc.testnet.fallback.example"]
+            credentials_dir = ".near-credentials/testnet/"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.rpc.retries, 5);
+        assert_eq!(
+            config.rpc.initial_backoff_ms,
+            RpcConfig::default().initial_backoff_ms
+        );
+        assert_eq!(
+            config.network("testnet").unwrap().fallback_rpc_urls,
+            vec!["https://rpc.testnet.fallback.example".parse().unwrap()]
+        );
+    }
+
+    #[test]
+    fn config_rpc_headers() {
+        let config = Config::from_toml(
+            r#"
+            [[networks]]
+            network_name = "testnet"
+            rpc_url = "https://rpc.testnet.near.org"
+            credentials_dir = ".near-credentials/testnet/"
+
+            [networks.rpc_headers]
+            x-api-key = "${NEAR_CLI_TEST_RPC_API_KEY}"
+            "#,
+        )
+        .unwrap();
+        let rpc_headers = &config.network("testnet").unwrap().rpc_headers;
+        std::env::set_var("NEAR_CLI_TEST_RPC_API_KEY", "secret");
+        assert_eq!(
+            expand_env_vars(&rpc_headers["x-api-key"]),
+            Ok("secret".to_string())
+        );
+        assert_eq!(
+            expand_env_vars("Bearer ${NEAR_CLI_TEST_RPC_API_KEY}"),
+            Ok("Bearer secret".to_string())
+        );
+        assert_eq!(
+            expand_env_vars("${NEAR_CLI_TEST_UNSET_VAR}"),
+            Err("environment variable NEAR_CLI_TEST_UNSET_VAR is not set".to_string())
+        );
+        assert!(expand_env_vars("${NEAR_CLI_TEST_RPC_API_KEY").is_err());
+    }
+
+    #[test]
+    fn config_duplicate_network_name() {
+        let config_toml = toml::to_string(&Config {
+            rpc: RpcConfig::default(),
+            keychain: KeychainConfig::default(),
+            networks: vec![
+                Config::default().networks[0].clone(),
+                Config::default().networks[0].clone(),
+            ],
+        })
+        .unwrap();
+        assert_eq!(
+            Config::from_toml(&config_toml),
+            Err("network <testnet> is defined more than once".to_string())
+        );
+    }
+
+    #[test]
+    fn config_without_networks() {
+        assert_eq!(
+            Config::from_toml("networks = []"),
+            Err("at least one network must be defined".to_string())
+        );
+    }
+}
@@ -0,0 +1,728 @@
+use std::io::Write;
+#[cfg(unix)]
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt, PermissionsExt};
+
+use chacha20poly1305::aead::{Aead, NewAead};
+
+/// Answers the passphrase prompt, e.g. in CI
+const PASSPHRASE_ENV_VAR: &str = "NEAR_KEYCHAIN_PASSPHRASE";
+
+/// scrypt cost parameters of newly encrypted files: 2^15 iterations, about 32 MiB of memory
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+/// A key file encrypted with a passphrase. The account ID and the public key stay readable, so
+/// keys can be listed and matched with the on-chain access keys without the passphrase.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct EncryptedKeyFile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    public_key: Option<String>,
+    encrypted: EncryptedData,
+}
+
+/// The plaintext key file sealed with XChaCha20-Poly1305 under a scrypt-derived key
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct EncryptedData {
+    kdf: String,
+    log_n: u8,
+    r: u32,
+    p: u32,
+    /// base64
+    salt: String,
+    cipher: String,
+    /// base64
+    nonce: String,
+    /// base64
+    ciphertext: String,
+}
+
+thread_local! {
+    /// Asked once per run even when several key files are read or written
+    static PASSPHRASE: std::cell::RefCell<Option<String>> = std::cell::RefCell::new(None);
+}
+
+pub fn is_encrypted(key_file: &serde_json::Value) -> bool {
+    key_file.get("encrypted").is_some()
+}
+
+/// Reads a key file, decrypting it when it is encrypted.
+pub fn read_key_file(path: &std::path::Path) -> color_eyre::eyre::Result<serde_json::Value> {
+    let data = std::fs::read_to_string(path).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "Access key file {} not found! Error: {}",
+            path.display(),
+            err
+        ))
+    })?;
+    let key_file: serde_json::Value = serde_json::from_str(&data).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "Error reading data from {}: {}",
+            path.display(),
+            err
+        ))
+    })?;
+    if !is_encrypted(&key_file) {
+        return Ok(key_file);
+    }
+    decrypt_key_file(&key_file, &passphrase(false)?).map_err(|err| {
+        // Let the next attempt ask again
+        PASSPHRASE.with(|passphrase| passphrase.borrow_mut().take());
+        color_eyre::Report::msg(format!("{}: {}", path.display(), err))
+    })
+}
+
+/// Writes a key file, encrypted if the config file says so (`[keychain] encrypt = true`); a file
+/// without a private key, e.g. of a Ledger key, has nothing to encrypt.
+pub fn write_key_file(path: &std::path::Path, key_file: &serde_json::Value) -> crate::CliResult {
+    let key_file = if crate::config::Config::load()?.keychain.encrypt
+        && key_file.get("private_key").is_some()
+    {
+        encrypt_key_file(key_file, &passphrase(true)?)?
+    } else {
+        key_file.clone()
+    };
+    write_private_file(path, key_file.to_string().as_bytes())
+}
+
+/// Creates the directory (and its missing parents) accessible by the owner only (0700).
+pub fn create_private_dir(path: &std::path::Path) -> crate::CliResult {
+    let mut dir_builder = std::fs::DirBuilder::new();
+    dir_builder.recursive(true);
+    #[cfg(unix)]
+    dir_builder.mode(0o700);
+    dir_builder.create(path).map_err(|err| {
+        color_eyre::Report::msg(format!("Failed to create {}: {}", path.display(), err))
+    })?;
+    // The mode is applied to new directories only
+    #[cfg(unix)]
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o700))?;
+    Ok(())
+}
+
+/// Writes a file readable by the owner only (0600), also when it already exists.
+pub fn write_private_file(path: &std::path::Path, contents: &[u8]) -> crate::CliResult {
+    let mut open_options = std::fs::OpenOptions::new();
+    open_options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    open_options.mode(0o600);
+    let mut file = open_options
+        .open(path)
+        .map_err(|err| color_eyre::Report::msg(format!("Failed to create file: {:?}", err)))?;
+    #[cfg(unix)]
+    file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    file.write_all(contents)
+        .map_err(|err| color_eyre::Report::msg(format!("Failed to write to file: {:?}", err)))?;
+    Ok(())
+}
+
+/// The permission bits of a file or a directory that gives access to other users, if it does.
+#[cfg(unix)]
+pub fn permissive_mode(path: &std::path::Path) -> Option<u32> {
+    let mode = std::fs::metadata(path).ok()?.permissions().mode() & 0o777;
+    if mode & 0o077 != 0 {
+        Some(mode)
+    } else {
+        None
+    }
+}
+
+#[cfg(not(unix))]
+pub fn permissive_mode(_path: &std::path::Path) -> Option<u32> {
+    None
+}
+
+pub fn encrypt_key_file(
+    key_file: &serde_json::Value,
+    passphrase: &str,
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    let mut salt = [0u8; 32];
+    let mut nonce = [0u8; 24];
+    rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut salt);
+    rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut nonce);
+    let key = derive_key(passphrase, &salt, SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P)?;
+    let ciphertext =
+        chacha20poly1305::XChaCha20Poly1305::new(chacha20poly1305::Key::from_slice(&key))
+            .encrypt(
+                chacha20poly1305::XNonce::from_slice(&nonce),
+                key_file.to_string().as_bytes(),
+            )
+            .map_err(|_| color_eyre::Report::msg("Failed to encrypt the key file"))?;
+    let encrypted_key_file = EncryptedKeyFile {
+        account_id: key_file["account_id"].as_str().map(str::to_owned),
+        public_key: key_file["public_key"].as_str().map(str::to_owned),
+        encrypted: EncryptedData {
+            kdf: "scrypt".to_owned(),
+            log_n: SCRYPT_LOG_N,
+            r: SCRYPT_R,
+            p: SCRYPT_P,
+            salt: base64::encode(&salt),
+            cipher: "xchacha20poly1305".to_owned(),
+            nonce: base64::encode(&nonce),
+            ciphertext: base64::encode(&ciphertext),
+        },
+    };
+    Ok(serde_json::to_value(encrypted_key_file)?)
+}
+
+pub fn decrypt_key_file(
+    key_file: &serde_json::Value,
+    passphrase: &str,
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    let encrypted_key_file: EncryptedKeyFile = serde_json::from_value(key_file.clone())
+        .map_err(|err| color_eyre::Report::msg(format!("Invalid encrypted key file: {}", err)))?;
+    let encrypted = encrypted_key_file.encrypted;
+    if encrypted.kdf != "scrypt" || encrypted.cipher != "xchacha20poly1305" {
+        return Err(color_eyre::Report::msg(format!(
+            "Unsupported key file encryption: {} with {}",
+            encrypted.kdf, encrypted.cipher
+        )));
+    }
+    let decode = |field: &str, value: &str| {
+        base64::decode(value).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Invalid {} of the encrypted key file: {}",
+                field, err
+            ))
+        })
+    };
+    let salt = decode("salt", &encrypted.salt)?;
+    let nonce = decode("nonce", &encrypted.nonce)?;
+    let ciphertext = decode("ciphertext", &encrypted.ciphertext)?;
+    if nonce.len() != 24 {
+        return Err(color_eyre::Report::msg(
+            "Invalid nonce of the encrypted key file",
+        ));
+    }
+    let key = derive_key(passphrase, &salt, encrypted.log_n, encrypted.r, encrypted.p)?;
+    let plaintext =
+        chacha20poly1305::XChaCha20Poly1305::new(chacha20poly1305::Key::from_slice(&key))
+            .decrypt(
+                chacha20poly1305::XNonce::from_slice(&nonce),
+                ciphertext.as_ref(),
+            )
+            .map_err(|_| {
+                color_eyre::Report::msg("Wrong passphrase or the key file is corrupted")
+            })?;
+    Ok(serde_json::from_slice(&plaintext)?)
+}
+
+/// A key file of the keychain. Only the fields that stay readable in encrypted key files are
+/// parsed; `read_key_file` gives the rest.
+#[derive(Debug, Clone)]
+pub struct KeychainKey {
+    pub account_id: String,
+    pub public_key: String,
+    pub path: std::path::PathBuf,
+    pub encrypted: bool,
+}
+
+/// Lists the keys saved under `credentials_dir`, both as `<account_id>/<public_key>.json` and as
+/// `<account_id>.json`; a key saved in both places is listed once.
+pub fn list_keys(credentials_dir: &std::path::Path) -> color_eyre::eyre::Result<Vec<KeychainKey>> {
+    let mut keys: Vec<KeychainKey> = vec![];
+    for key in scan_key_files(credentials_dir)? {
+        if !keys.iter().any(|listed_key| {
+            listed_key.account_id == key.account_id && listed_key.public_key == key.public_key
+        }) {
+            keys.push(key);
+        }
+    }
+    Ok(keys)
+}
+
+/// The directories to look for the keys of an account in: the `credentials_dir` of the network,
+/// then `~/.near-credentials/<network>/` where the JavaScript near-cli keeps them, then the legacy
+/// `~/.near-credentials/default/`. Without a network every network is searched after `default/`.
+pub fn credentials_search_path(
+    connection_config: Option<&crate::common::ConnectionConfig>,
+) -> color_eyre::eyre::Result<Vec<std::path::PathBuf>> {
+    let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
+    let legacy_dir = home_dir.join(crate::consts::DIR_NAME_KEY_CHAIN);
+    let mut credentials_dirs = vec![];
+    match connection_config {
+        Some(connection_config) => {
+            credentials_dirs.push(connection_config.credentials_path());
+            credentials_dirs.push(
+                home_dir
+                    .join(".near-credentials")
+                    .join(connection_config.network_name()),
+            );
+            credentials_dirs.push(legacy_dir);
+        }
+        None => {
+            credentials_dirs.push(legacy_dir);
+            for connection_config in crate::common::ConnectionConfig::preselected_or_all_networks()?
+            {
+                credentials_dirs.push(connection_config.credentials_path());
+            }
+        }
+    }
+    let mut unique_credentials_dirs: Vec<std::path::PathBuf> = vec![];
+    for credentials_dir in credentials_dirs {
+        // `credentials_dir` is written with a trailing slash in the config file
+        let credentials_dir = credentials_dir.components().collect::<std::path::PathBuf>();
+        if !unique_credentials_dirs.contains(&credentials_dir) {
+            unique_credentials_dirs.push(credentials_dir);
+        }
+    }
+    Ok(unique_credentials_dirs)
+}
+
+/// The keys from all the directories, in the order of the directories; a key found in several of
+/// them is listed once, with the path of the first one.
+pub fn find_keys(
+    credentials_dirs: &[std::path::PathBuf],
+) -> color_eyre::eyre::Result<Vec<KeychainKey>> {
+    let mut keys: Vec<KeychainKey> = vec![];
+    for credentials_dir in credentials_dirs {
+        for key in list_keys(credentials_dir)? {
+            if !keys.iter().any(|listed_key| {
+                listed_key.account_id == key.account_id && listed_key.public_key == key.public_key
+            }) {
+                keys.push(key);
+            }
+        }
+    }
+    Ok(keys)
+}
+
+/// The keys of the account from all the directories, as `find_keys` lists them.
+pub fn find_account_keys(
+    credentials_dirs: &[std::path::PathBuf],
+    account_id: &str,
+) -> color_eyre::eyre::Result<Vec<KeychainKey>> {
+    Ok(find_keys(credentials_dirs)?
+        .into_iter()
+        .filter(|key| key.account_id == account_id)
+        .collect())
+}
+
+/// The `<account_id>.json` file in the format of near-api-js (`UnencryptedFileSystemKeyStore`),
+/// so the JavaScript tools can use the keys saved by near-cli.
+pub fn js_compatible_key_file(key_file: &serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "account_id": key_file["account_id"],
+        "public_key": key_file["public_key"],
+        "private_key": key_file["private_key"],
+    })
+}
+
+/// Removes every file of the key and the account directory if it is left empty.
+pub fn remove_key(
+    credentials_dir: &std::path::Path,
+    account_id: &str,
+    public_key: &str,
+) -> color_eyre::eyre::Result<Vec<std::path::PathBuf>> {
+    let mut removed_paths = vec![];
+    for key in scan_key_files(credentials_dir)? {
+        if key.account_id == account_id && key.public_key == public_key {
+            std::fs::remove_file(&key.path).map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to remove {}: {}", key.path.display(), err))
+            })?;
+            removed_paths.push(key.path);
+        }
+    }
+    // Fails, as it should, while the directory still has other keys
+    let _ = std::fs::remove_dir(credentials_dir.join(account_id));
+    Ok(removed_paths)
+}
+
+/// All the key files, including the ones that hold the same key. `<account_id>/<public_key>.json`
+/// files come first, so they win over `<account_id>.json`.
+pub fn scan_key_files(
+    credentials_dir: &std::path::Path,
+) -> color_eyre::eyre::Result<Vec<KeychainKey>> {
+    let mut account_dirs = vec![];
+    let mut account_files = vec![];
+    if credentials_dir.is_dir() {
+        for entry in std::fs::read_dir(credentials_dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                account_dirs.push(path);
+            } else {
+                account_files.push(path);
+            }
+        }
+    }
+    account_dirs.sort();
+    account_files.sort();
+    let mut keys = vec![];
+    for account_dir in account_dirs {
+        let account_id = match account_dir.file_name().and_then(|name| name.to_str()) {
+            Some(account_id) => account_id.to_owned(),
+            None => continue,
+        };
+        let mut key_paths = std::fs::read_dir(&account_dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .collect::<Vec<_>>();
+        key_paths.sort();
+        for key_path in key_paths {
+            keys.extend(read_keychain_key(key_path, &account_id));
+        }
+    }
+    for account_file in account_files {
+        let account_id = match account_file.file_stem().and_then(|name| name.to_str()) {
+            Some(account_id) => account_id.to_owned(),
+            None => continue,
+        };
+        keys.extend(read_keychain_key(account_file, &account_id));
+    }
+    Ok(keys)
+}
+
+fn read_keychain_key(path: std::path::PathBuf, account_id: &str) -> Option<KeychainKey> {
+    if path.extension().and_then(|extension| extension.to_str()) != Some("json") {
+        return None;
+    }
+    let key_file: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).ok()?).ok()?;
+    // near-api-js only needs the private key, some older files have no public key
+    let public_key = match key_file["public_key"].as_str() {
+        Some(public_key) => public_key.to_owned(),
+        None => key_file["private_key"]
+            .as_str()
+            .or_else(|| key_file["secret_key"].as_str())?
+            .parse::<near_crypto::SecretKey>()
+            .ok()?
+            .public_key()
+            .to_string(),
+    };
+    Some(KeychainKey {
+        account_id: account_id.to_owned(),
+        public_key,
+        encrypted: is_encrypted(&key_file),
+        path,
+    })
+}
+
+/// The result of `encrypt_existing_key_files`
+#[derive(Debug, Default)]
+pub struct EncryptedKeyFiles {
+    pub encrypted_paths: Vec<std::path::PathBuf>,
+    /// The plaintext `<account_id>.json` copies of near-api-js
+    pub removed_paths: Vec<std::path::PathBuf>,
+}
+
+/// Encrypts the plaintext key files found under `dir` (recursively) in place. Already encrypted
+/// files and JSON files without a private key are left as they are. near-api-js cannot read
+/// encrypted files, so its `<account_id>.json` copies right under `dir` are removed instead; a key
+/// that has no other copy is first saved encrypted to `<account_id>/<public_key>.json`.
+pub fn encrypt_existing_key_files(
+    dir: &std::path::Path,
+) -> color_eyre::eyre::Result<EncryptedKeyFiles> {
+    let mut encrypted_key_files = EncryptedKeyFiles::default();
+    encrypt_key_files(dir, true, &mut encrypted_key_files)?;
+    Ok(encrypted_key_files)
+}
+
+fn encrypt_key_files(
+    dir: &std::path::Path,
+    is_credentials_dir: bool,
+    encrypted_key_files: &mut EncryptedKeyFiles,
+) -> crate::CliResult {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    let entries = std::fs::read_dir(dir).map_err(|err| {
+        color_eyre::Report::msg(format!("Failed to read {}: {}", dir.display(), err))
+    })?;
+    let mut paths = entries
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    // The account directories go first, so a copy of the key is found there when it is saved
+    paths.sort_by_key(|path| !path.is_dir());
+    for path in paths {
+        if path.is_dir() {
+            encrypt_key_files(&path, false, encrypted_key_files)?;
+            continue;
+        }
+        if path.extension().and_then(|extension| extension.to_str()) != Some("json") {
+            continue;
+        }
+        let key_file: serde_json::Value = match std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+        {
+            Some(key_file) => key_file,
+            None => continue,
+        };
+        if is_encrypted(&key_file) || key_file.get("private_key").is_none() {
+            continue;
+        }
+        if is_credentials_dir
+            && key_file.get("master_seed_phrase").is_none()
+            && key_file.get("seed_phrase_hd_path").is_none()
+        {
+            let account_id = match path.file_stem().and_then(|name| name.to_str()) {
+                Some(account_id) => account_id.to_owned(),
+                None => continue,
+            };
+            let key = match read_keychain_key(path.clone(), &account_id) {
+                Some(key) => key,
+                None => continue,
+            };
+            let account_dir = dir.join(&account_id);
+            let key_path = account_dir.join(format!("{}.json", key.public_key.replace(":", "_")));
+            if !key_path.exists() {
+                create_private_dir(&account_dir)?;
+                let encrypted_key_file = encrypt_key_file(&key_file, &passphrase(true)?)?;
+                write_private_file(&key_path, encrypted_key_file.to_string().as_bytes())?;
+                encrypted_key_files.encrypted_paths.push(key_path);
+            }
+            std::fs::remove_file(&path).map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to remove {}: {}", path.display(), err))
+            })?;
+            encrypted_key_files.removed_paths.push(path);
+            continue;
+        }
+        let encrypted_key_file = encrypt_key_file(&key_file, &passphrase(true)?)?;
+        write_private_file(&path, encrypted_key_file.to_string().as_bytes())?;
+        encrypted_key_files.encrypted_paths.push(path);
+    }
+    Ok(())
+}
+
+fn derive_key(
+    passphrase: &str,
+    salt: &[u8],
+    log_n: u8,
+    r: u32,
+    p: u32,
+) -> color_eyre::eyre::Result<[u8; 32]> {
+    let params = scrypt::Params::new(log_n, r, p)
+        .map_err(|err| color_eyre::Report::msg(format!("Invalid scrypt parameters: {}", err)))?;
+    let mut key = [0u8; 32];
+    scrypt::scrypt(passphrase.as_bytes(), salt, &params, &mut key)
+        .map_err(|err| color_eyre::Report::msg(format!("Failed to derive the key: {}", err)))?;
+    Ok(key)
+}
+
+/// The passphrase comes from `NEAR_KEYCHAIN_PASSPHRASE` or a hidden prompt; a new passphrase
+/// (used for encryption) has to be typed twice.
+fn passphrase(new: bool) -> color_eyre::eyre::Result<String> {
+    if let Some(passphrase) = PASSPHRASE.with(|passphrase| passphrase.borrow().clone()) {
+        return Ok(passphrase);
+    }
+    let passphrase = match std::env::var(PASSPHRASE_ENV_VAR) {
+        Ok(passphrase) => passphrase,
+        Err(_) => {
+            crate::common::ensure_interactive("keychain-passphrase")?;
+            let prompt = dialoguer::Password::new().with_prompt("Enter the keychain passphrase");
+            if new {
+                prompt
+                    .with_confirmation("Repeat the passphrase", "The passphrases don't match")
+                    .interact()?
+            } else {
+                prompt.interact()?
+            }
+        }
+    };
+    PASSPHRASE.with(|cached_passphrase| *cached_passphrase.borrow_mut() = Some(passphrase.clone()));
+    Ok(passphrase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_file_encryption_round_trip() {
+        let key_file = serde_json::json!({
+            "account_id": "volodymyr.testnet",
+            "public_key": "ed25519:7FmDRADa1v4BcLiiR9MPPdmWQp3Um1iPdAYATvBY1YzS",
+            "private_key": "ed25519:3UxxZHyZRoFGcnjEdBVSBNRGEJHU6Xt8cx3gN5s5H4YiQpbjXVR7Lq6NFAsPhpHtuNs5ghJxeRdHizqBFCSkDvDr",
+        });
+        let encrypted_key_file = encrypt_key_file(&key_file, "passphrase").unwrap();
+        assert!(is_encrypted(&encrypted_key_file));
+        assert!(!encrypted_key_file.to_string().contains("private_key"));
+        assert_eq!(encrypted_key_file["public_key"], key_file["public_key"]);
+        assert_eq!(
+            decrypt_key_file(&encrypted_key_file, "passphrase").unwrap(),
+            key_file
+        );
+        assert!(decrypt_key_file(&encrypted_key_file, "wrong passphrase").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn key_files_are_private() {
+        let credentials_dir = std::env::temp_dir().join("near-cli-test-private-keychain");
+        let _ = std::fs::remove_dir_all(&credentials_dir);
+        let account_dir = credentials_dir.join("volodymyr.testnet");
+        create_private_dir(&account_dir).unwrap();
+        let key_path = account_dir.join("ed25519_key.json");
+        std::fs::write(&key_path, "{}").unwrap();
+        std::fs::set_permissions(&key_path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        assert_eq!(permissive_mode(&key_path), Some(0o644));
+
+        write_private_file(&key_path, b"{}").unwrap();
+        assert_eq!(permissive_mode(&key_path), None);
+        assert_eq!(permissive_mode(&account_dir), None);
+        assert_eq!(permissive_mode(&credentials_dir), None);
+        std::fs::remove_dir_all(&credentials_dir).unwrap();
+    }
+
+    #[test]
+    fn keychain_list_and_remove_keys() {
+        let credentials_dir = std::env::temp_dir().join("near-cli-test-keychain");
+        let _ = std::fs::remove_dir_all(&credentials_dir);
+        std::fs::create_dir_all(credentials_dir.join("volodymyr.testnet")).unwrap();
+        let write = |path: &str, public_key: &str| {
+            let key_file = serde_json::json!({
+                "account_id": "volodymyr.testnet",
+                "public_key": public_key,
+                "private_key": "ed25519:secret",
+            });
+            std::fs::write(credentials_dir.join(path), key_file.to_string()).unwrap();
+        };
+        write("volodymyr.testnet/ed25519_first.json", "ed25519:first");
+        write("volodymyr.testnet/ed25519_second.json", "ed25519:second");
+        write("volodymyr.testnet.json", "ed25519:first");
+
+        let keys = list_keys(&credentials_dir).unwrap();
+        assert_eq!(
+            keys.iter()
+                .map(|key| (key.account_id.as_str(), key.public_key.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("volodymyr.testnet", "ed25519:first"),
+                ("volodymyr.testnet", "ed25519:second"),
+            ]
+        );
+
+        let removed_paths =
+            remove_key(&credentials_dir, "volodymyr.testnet", "ed25519:first").unwrap();
+        assert_eq!(removed_paths.len(), 2);
+        assert_eq!(list_keys(&credentials_dir).unwrap().len(), 1);
+        remove_key(&credentials_dir, "volodymyr.testnet", "ed25519:second").unwrap();
+        assert!(!credentials_dir.join("volodymyr.testnet").exists());
+        std::fs::remove_dir_all(&credentials_dir).unwrap();
+    }
+
+    #[test]
+    fn js_key_files_are_removed_when_encrypting() {
+        let credentials_dir = std::env::temp_dir().join("near-cli-test-encrypt-keychain");
+        let _ = std::fs::remove_dir_all(&credentials_dir);
+        std::fs::create_dir_all(credentials_dir.join("volodymyr.testnet")).unwrap();
+        let key_file = serde_json::json!({
+            "account_id": "volodymyr.testnet",
+            "public_key": "ed25519:7FmDRADa1v4BcLiiR9MPPdmWQp3Um1iPdAYATvBY1YzS",
+            "private_key": "ed25519:3UxxZHyZRoFGcnjEdBVSBNRGEJHU6Xt8cx3gN5s5H4YiQpbjXVR7Lq6NFAsPhpHtuNs5ghJxeRdHizqBFCSkDvDr",
+        });
+        let key_path = credentials_dir
+            .join("volodymyr.testnet")
+            .join("ed25519_7FmDRADa1v4BcLiiR9MPPdmWQp3Um1iPdAYATvBY1YzS.json");
+        let js_key_path = credentials_dir.join("volodymyr.testnet.json");
+        // A key saved by near-api-js only
+        let js_only_key_path = credentials_dir
+            .join("frovolod.testnet")
+            .join("ed25519_7FmDRADa1v4BcLiiR9MPPdmWQp3Um1iPdAYATvBY1YzS.json");
+        let js_only_key_file_path = credentials_dir.join("frovolod.testnet.json");
+        std::fs::write(&key_path, key_file.to_string()).unwrap();
+        std::fs::write(&js_key_path, key_file.to_string()).unwrap();
+        let mut js_only_key_file = key_file.clone();
+        js_only_key_file["account_id"] = serde_json::json!("frovolod.testnet");
+        std::fs::write(&js_only_key_file_path, js_only_key_file.to_string()).unwrap();
+        PASSPHRASE.with(|passphrase| *passphrase.borrow_mut() = Some("passphrase".to_owned()));
+        let read_json = |path: &std::path::Path| -> serde_json::Value {
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+        };
+
+        let mut encrypted_key_files = encrypt_existing_key_files(&credentials_dir).unwrap();
+        encrypted_key_files.encrypted_paths.sort();
+        encrypted_key_files.removed_paths.sort();
+        assert_eq!(
+            encrypted_key_files.encrypted_paths,
+            vec![js_only_key_path.clone(), key_path.clone()]
+        );
+        assert_eq!(
+            encrypted_key_files.removed_paths,
+            vec![js_only_key_file_path, js_key_path]
+        );
+        assert!(is_encrypted(&read_json(&key_path)));
+        assert_eq!(
+            decrypt_key_file(&read_json(&js_only_key_path), "passphrase").unwrap(),
+            js_only_key_file
+        );
+        std::fs::remove_dir_all(&credentials_dir).unwrap();
+    }
+
+    /// Sample key files written by the JavaScript near-cli / near-api-js and by near-cli itself
+    #[test]
+    fn js_credential_files_conformance() {
+        let credentials_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/credentials/testnet");
+        let keys = list_keys(&credentials_dir).unwrap();
+        assert_eq!(
+            keys.iter()
+                .map(|key| (key.account_id.as_str(), key.public_key.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "rust-cli.testnet",
+                    "ed25519:6j4b6zUaty6fD1awqcGCCU9JYGCWYUgdJhQrzfZhqE25"
+                ),
+                (
+                    "js-near-cli.testnet",
+                    "ed25519:AYNgBs6Hs5jYt6ekCzzariQthqAp66mpXm7q1yUE3W7"
+                ),
+                (
+                    "js-no-public-key.testnet",
+                    "ed25519:3ZwsDvLXeDALWiL46mxDp8LXLDfBrwsBsDt4rdbqxTEr"
+                ),
+                (
+                    "js-secret-key.testnet",
+                    "ed25519:B8pNzYV3FEMSSSmHEuuceDwJhaLhFCTwBwBBtJp7U3Qu"
+                ),
+            ]
+        );
+        for key in keys.iter() {
+            let key_file = read_key_file(&key.path).unwrap();
+            let private_key: near_crypto::SecretKey = key_file["private_key"]
+                .as_str()
+                .or_else(|| key_file["secret_key"].as_str())
+                .unwrap()
+                .parse()
+                .unwrap();
+            assert_eq!(private_key.public_key().to_string(), key.public_key);
+        }
+
+        // The seed phrase of a near-cli key file derives its key
+        let key_file = read_key_file(&keys[0].path).unwrap();
+        let key_pair_properties = crate::common::get_key_pair_properties_from_seed_phrase(
+            key_file["master_seed_phrase"].as_str().unwrap().to_owned(),
+            key_file["seed_phrase_hd_path"]
+                .as_str()
+                .unwrap()
+                .parse()
+                .unwrap(),
+            near_crypto::KeyType::ED25519,
+            "",
+        )
+        .unwrap();
+        assert_eq!(key_pair_properties.public_key_str, keys[0].public_key);
+        assert_eq!(
+            key_pair_properties.secret_keypair_str,
+            key_file["private_key"]
+        );
+
+        // near-cli writes `<account_id>.json` exactly as near-api-js does
+        let account_file: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(credentials_dir.join("rust-cli.testnet.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(js_compatible_key_file(&key_file), account_file);
+        let js_account_file = read_key_file(&keys[1].path).unwrap();
+        assert_eq!(js_compatible_key_file(&js_account_file), js_account_file);
+
+        assert_eq!(
+            find_account_keys(&[credentials_dir], "js-near-cli.testnet")
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
@@ -1,9 +1,13 @@
 use clap::Clap;
+use std::io::Write;
 extern crate shell_words;
 
 mod commands;
 mod common;
+mod config;
 mod consts;
+mod keychain;
+mod rpc_client;
 
 type CliResult = color_eyre::eyre::Result<()>;
 
@@ -19,6 +23,27 @@ type CliResult = color_eyre::eyre::Result<()>;
     // setting(clap::AppSettings::NextLineHelp)
 )]
 struct CliArgs {
+    /// The network from the config file to use in every command; defaults to the NEAR_NETWORK env var
+    #[clap(long)]
+    network: Option<self::common::ConnectionConfig>,
+    /// Log every JSON-RPC request and response to stderr; also enabled by the NEAR_CLI_LOG env var
+    #[clap(long)]
+    verbose: bool,
+    /// Save every JSON-RPC request and response of this run to a fixture file
+    #[clap(long, conflicts_with = "replay")]
+    record: Option<std::path::PathBuf>,
+    /// Answer JSON-RPC requests from a fixture file saved with --record instead of the network
+    #[clap(long)]
+    replay: Option<std::path::PathBuf>,
+    /// plaintext or json; with json the result is printed to stdout as a JSON object and all other text goes to stderr
+    #[clap(long, default_value = "plaintext")]
+    output: self::common::OutputFormat,
+    /// Fail with an error instead of prompting for missing arguments; implied when stdin is not a terminal
+    #[clap(long)]
+    no_interactive: bool,
+    /// Do not print "Your console command" at the end; it goes to stderr when stdout is not a terminal
+    #[clap(long)]
+    no_echo_command: bool,
     #[clap(subcommand)]
     top_level_command: Option<self::commands::CliTopLevelCommand>,
 }
@@ -43,18 +68,25 @@ impl CliArgs {
 impl From<Args> for CliArgs {
     fn from(cli_args: Args) -> Self {
         Self {
+            network: None,
+            verbose: false,
+            record: None,
+            replay: None,
+            output: Default::default(),
+            no_interactive: false,
+            no_echo_command: false,
             top_level_command: Some(cli_args.top_level_command.into()),
         }
     }
 }
 
-impl From<CliArgs> for Args {
-    fn from(cli_args: CliArgs) -> Self {
+impl Args {
+    fn from(cli_args: CliArgs) -> color_eyre::eyre::Result<Self> {
         let top_level_command = match cli_args.top_level_command {
-            Some(cli_subcommand) => self::commands::TopLevelCommand::from(cli_subcommand),
-            None => self::commands::TopLevelCommand::choose_command(),
+            Some(cli_subcommand) => self::commands::TopLevelCommand::from(cli_subcommand)?,
+            None => self::commands::TopLevelCommand::choose_command()?,
         };
-        Self { top_level_command }
+        Ok(Self { top_level_command })
     }
 }
 
@@ -64,8 +96,31 @@ impl Args {
     }
 }
 
+/// With a network preselected by `--network` or NEAR_NETWORK the `network` keyword of the
+/// operation mode can be left out; it is put back at the first place where the command parses.
+fn parse_cli_args(args: Vec<String>) -> CliArgs {
+    let error = match CliArgs::try_parse_from(&args) {
+        Ok(cli) => return cli,
+        Err(error) => error,
+    };
+    let network_preselected = std::env::var_os("NEAR_NETWORK").is_some()
+        || args
+            .iter()
+            .any(|arg| arg == "--network" || arg.starts_with("--network="));
+    if network_preselected && !args.iter().any(|arg| arg == "network") {
+        for position in 1..=args.len() {
+            let mut network_args = args.clone();
+            network_args.insert(position, "network".to_owned());
+            if let Ok(cli) = CliArgs::try_parse_from(&network_args) {
+                return cli;
+            }
+        }
+    }
+    error.exit()
+}
+
 fn main() -> CliResult {
-    let cli = CliArgs::parse();
+    let cli = parse_cli_args(std::env::args().collect());
 
     if let Some(self::commands::CliTopLevelCommand::GenerateShellCompletions(subcommand)) =
         cli.top_level_command
@@ -74,18 +129,98 @@ fn main() -> CliResult {
         return Ok(());
     }
 
-    let args = Args::from(cli);
+    let network = match cli.network.clone() {
+        Some(network) => Some(network),
+        None => match std::env::var("NEAR_NETWORK") {
+            Ok(network_name) => Some(
+                network_name
+                    .parse::<self::common::ConnectionConfig>()
+                    .map_err(|err| color_eyre::Report::msg(format!("NEAR_NETWORK: {}", err)))?,
+            ),
+            Err(_) => None,
+        },
+    };
+    if let Some(network) = network {
+        self::common::ConnectionConfig::preselect_network(network);
+    }
+
+    let log_from_env = std::env::var("NEAR_CLI_LOG")
+        .map(|value| !["", "0", "false"].contains(&value.as_str()))
+        .unwrap_or(false);
+    if cli.verbose || log_from_env {
+        self::rpc_client::enable_tracing();
+    }
+
+    if let Some(fixture_path) = &cli.replay {
+        self::rpc_client::start_replay(fixture_path)?;
+    }
+    if cli.record.is_some() {
+        self::rpc_client::start_recording();
+    }
+    let record = cli.record.clone();
+    let no_echo_command = cli.no_echo_command;
 
-    let completed_cli = CliArgs::from(args.clone());
+    let json_stdout = match cli.output {
+        self::common::OutputFormat::Json => Some(self::common::redirect_stdout_to_stderr()?),
+        self::common::OutputFormat::Plaintext => None,
+    };
+    self::common::OutputFormat::set_global(cli.output.clone());
+
+    if cli.no_interactive || !atty::is(atty::Stream::Stdin) {
+        self::common::disable_prompts(shell_words::join(&cli.to_cli_args()));
+    }
 
     color_eyre::install()?;
 
-    actix::System::new().block_on(args.process());
+    // A missing argument in non-interactive mode fails here, still leaving the output and the recording
+    let (result, completed_cli) = match Args::from(cli) {
+        Ok(args) => {
+            let completed_cli = CliArgs::from(args.clone());
+            (
+                actix::System::new().block_on(args.process()),
+                Some(completed_cli),
+            )
+        }
+        Err(err) => (Err(err), None),
+    };
+
+    if let Some(fixture_path) = record {
+        self::rpc_client::save_recording(&fixture_path)?;
+    }
+
+    match completed_cli {
+        Some(completed_cli) if !no_echo_command => {
+            let console_command = format!(
+                "Your console command:\n{}",
+                shell_words::join(&completed_cli.to_cli_args())
+            );
+            // Keep the echo out of pipes and redirected output
+            if atty::is(atty::Stream::Stdout) {
+                println!("{}", console_command);
+            } else {
+                eprintln!("{}", console_command);
+            }
+        }
+        _ => {}
+    }
 
-    println!(
-        "Your console command:\n{}",
-        shell_words::join(&completed_cli.to_cli_args())
-    );
+    if let Some(mut json_stdout) = json_stdout {
+        let mut json_output = self::common::take_json_output();
+        if let Err(err) = &result {
+            json_output["command_error"] = serde_json::json!(err
+                .chain()
+                .map(|cause| cause.to_string())
+                .collect::<Vec<_>>()
+                .join(": "));
+        }
+        std::io::stdout().flush()?;
+        writeln!(
+            json_stdout,
+            "{}",
+            serde_json::to_string_pretty(&json_output)?
+        )?;
+    }
 
-    Ok(())
+    // The error is reported on stderr and the exit status is non-zero
+    result
 }
@@ -0,0 +1,557 @@
+use near_primitives::borsh::BorshSerialize;
+
+type RpcError = near_jsonrpc_primitives::errors::RpcError;
+
+/// Responses with contract code or state can be large
+const RESPONSE_SIZE_LIMIT: usize = 256 * 1024 * 1024;
+
+/// Set by `--verbose` or the `NEAR_CLI_LOG` env var
+static VERBOSE: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// Makes every JSON-RPC request and its raw response to be logged to stderr.
+pub fn enable_tracing() {
+    VERBOSE.store(true, std::sync::atomic::Ordering::Relaxed);
+}
+
+fn is_tracing_enabled() -> bool {
+    VERBOSE.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+/// JSON-RPC client shared by all commands.
+///
+/// A failed request is repeated with an exponential backoff and, once the retries are used up,
+/// sent to the next fallback endpoint of the network. Errors about the request itself (unknown
+/// account, invalid transaction, ...) are returned right away.
+#[derive(Debug, Clone)]
+pub struct RpcClient {
+    urls: Vec<url::Url>,
+    /// Values are expanded right before each request, so the secrets taken from env vars never
+    /// show up in `Debug` output
+    headers: std::collections::BTreeMap<String, String>,
+    rpc_config: crate::config::RpcConfig,
+}
+
+enum Failure {
+    /// The request may succeed when repeated
+    Transient(RpcError),
+    Permanent(RpcError),
+}
+
+impl RpcClient {
+    pub fn new(
+        urls: Vec<url::Url>,
+        headers: std::collections::BTreeMap<String, String>,
+        rpc_config: crate::config::RpcConfig,
+    ) -> Self {
+        Self {
+            urls,
+            headers,
+            rpc_config,
+        }
+    }
+
+    pub async fn query(
+        &self,
+        request: near_jsonrpc_primitives::types::query::RpcQueryRequest,
+    ) -> Result<near_jsonrpc_primitives::types::query::RpcQueryResponse, RpcError> {
+        self.call("query", request).await
+    }
+
+    pub async fn status(&self) -> Result<near_primitives::views::StatusResponse, RpcError> {
+        self.call("status", serde_json::json!([])).await
+    }
+
+    /// The protocol config at the block (`EXPERIMENTAL_protocol_config`), with the runtime
+    /// costs such as the storage price.
+    pub async fn protocol_config(
+        &self,
+        block_reference: near_primitives::types::BlockReference,
+    ) -> Result<serde_json::Value, RpcError> {
+        self.call("EXPERIMENTAL_protocol_config", block_reference)
+            .await
+    }
+
+    pub async fn tx(
+        &self,
+        transaction_hash: String,
+        account_id: near_primitives::types::AccountId,
+    ) -> Result<near_primitives::views::FinalExecutionOutcomeView, RpcError> {
+        self.call("tx", serde_json::json!([transaction_hash, account_id]))
+            .await
+    }
+
+    /// Sends the transaction and waits for its outcome. The outcome of a failed attempt is
+    /// unknown, so the transaction status is looked up by hash before the transaction is sent
+    /// again; this way it is never submitted twice.
+    pub async fn broadcast_tx_commit(
+        &self,
+        signed_transaction: &near_primitives::transaction::SignedTransaction,
+    ) -> Result<near_primitives::views::FinalExecutionOutcomeView, RpcError> {
+        let params = serde_json::json!([near_primitives::serialize::to_base64(
+            signed_transaction
+                .try_to_vec()
+                .expect("Transaction is not expected to fail on serialization")
+        )]);
+        let tx_status_params = serde_json::json!([
+            signed_transaction.get_hash().to_string(),
+            signed_transaction.transaction.signer_id
+        ]);
+        let mut last_error = None;
+        for (url, backoff) in self.attempts() {
+            actix::clock::sleep(backoff).await;
+            if last_error.is_some() {
+                match self.send(&url, "tx", &tx_status_params).await {
+                    Ok(outcome) => return Ok(outcome),
+                    Err(Failure::Transient(err)) => {
+                        last_error = Some(err);
+                        continue;
+                    }
+                    // The network does not know the transaction, so it is safe to send it again
+                    Err(Failure::Permanent(_)) => {}
+                }
+            }
+            match self.send(&url, "broadcast_tx_commit", &params).await {
+                Ok(outcome) => return Ok(outcome),
+                Err(Failure::Transient(err)) => last_error = Some(err),
+                Err(Failure::Permanent(err)) => return Err(err),
+            }
+        }
+        Err(last_error.expect("At least one attempt is always made"))
+    }
+
+    pub async fn call<P, R>(&self, method: &str, params: P) -> Result<R, RpcError>
+    where
+        P: serde::Serialize,
+        R: serde::de::DeserializeOwned,
+    {
+        let params = serde_json::to_value(params)
+            .map_err(|err| rpc_error(format!("Failed to serialize the request: {}", err)))?;
+        let mut last_error = None;
+        for (url, backoff) in self.attempts() {
+            actix::clock::sleep(backoff).await;
+            match self.send(&url, method, &params).await {
+                Ok(result) => return Ok(result),
+                Err(Failure::Transient(err)) => last_error = Some(err),
+                Err(Failure::Permanent(err)) => return Err(err),
+            }
+        }
+        Err(last_error.expect("At least one attempt is always made"))
+    }
+
+    /// Every endpoint is tried `retries + 1` times before moving on to the next one.
+    fn attempts(&self) -> Vec<(url::Url, std::time::Duration)> {
+        let mut attempts = vec![];
+        for url in &self.urls {
+            for retry in 0..=self.rpc_config.retries {
+                let backoff_ms = if retry == 0 {
+                    0
+                } else {
+                    self.rpc_config
+                        .initial_backoff_ms
+                        .saturating_mul(2u64.saturating_pow(retry - 1))
+                };
+                attempts.push((url.clone(), std::time::Duration::from_millis(backoff_ms)));
+            }
+        }
+        attempts
+    }
+
+    async fn send<R: serde::de::DeserializeOwned>(
+        &self,
+        url: &url::Url,
+        method: &str,
+        params: &serde_json::Value,
+    ) -> Result<R, Failure> {
+        let response = match replayed_response(method, params) {
+            Some(response) => response.map_err(Failure::Permanent)?,
+            None => self.fetch(url, method, params).await?,
+        };
+        record_interaction(method, params, &response);
+        if let Some(error) = response.get("error") {
+            let rpc_error = serde_json::from_value(error.clone())
+                .unwrap_or_else(|_| rpc_error(error.to_string()));
+            return Err(if is_transient(error) {
+                Failure::Transient(rpc_error)
+            } else {
+                Failure::Permanent(rpc_error)
+            });
+        }
+        serde_json::from_value(response["result"].clone()).map_err(|err| {
+            Failure::Permanent(rpc_error(format!(
+                "Failed to parse the {} response: {}",
+                method, err
+            )))
+        })
+    }
+
+    async fn fetch(
+        &self,
+        url: &url::Url,
+        method: &str,
+        params: &serde_json::Value,
+    ) -> Result<serde_json::Value, Failure> {
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": "dontcare",
+            "method": method,
+            "params": params,
+        });
+        let client = awc::Client::builder()
+            .timeout(std::time::Duration::from_secs(
+                self.rpc_config.request_timeout_secs,
+            ))
+            .finish();
+        let mut request_builder = client.post(url.as_str());
+        for (name, value) in &self.headers {
+            let value = crate::config::expand_env_vars(value).map_err(|err| {
+                Failure::Permanent(rpc_error(format!("RPC header <{}>: {}", name, err)))
+            })?;
+            request_builder = request_builder.insert_header((name.as_str(), value));
+        }
+        if is_tracing_enabled() {
+            eprintln!("--> {} {} {}", url, method, redacted_params(method, params));
+        }
+        let started_at = std::time::Instant::now();
+        let response = async {
+            let mut response = request_builder
+                .send_json(&request)
+                .await
+                .map_err(|err| format!("{}: {}", url, err))?;
+            let body = response
+                .body()
+                .limit(RESPONSE_SIZE_LIMIT)
+                .await
+                .map_err(|err| format!("{}: {}", url, err))?;
+            Ok::<_, String>((response.status(), body))
+        }
+        .await;
+        if is_tracing_enabled() {
+            match &response {
+                Ok((status, body)) => eprintln!(
+                    "<-- {} in {} ms, HTTP {}: {}",
+                    method,
+                    started_at.elapsed().as_millis(),
+                    status,
+                    String::from_utf8_lossy(body)
+                ),
+                Err(err) => eprintln!(
+                    "<-- {} in {} ms, failed: {}",
+                    method,
+                    started_at.elapsed().as_millis(),
+                    err
+                ),
+            }
+        }
+        let (status, body) = response.map_err(|err| Failure::Transient(rpc_error(err)))?;
+        serde_json::from_slice(&body).map_err(|err| {
+            Failure::Transient(rpc_error(format!(
+                "{} responded with HTTP {} and an invalid JSON-RPC body: {}",
+                url, status, err
+            )))
+        })
+    }
+}
+
+/// A JSON-RPC conversation saved with `--record` and answered from with `--replay`
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct RpcFixture {
+    interactions: Vec<RpcInteraction>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+struct RpcInteraction {
+    method: String,
+    params: serde_json::Value,
+    /// The whole JSON-RPC response, so recorded errors are replayed as errors
+    response: serde_json::Value,
+}
+
+enum FixtureMode {
+    Record(RpcFixture),
+    Replay(RpcFixture),
+}
+
+thread_local! {
+    static FIXTURE_MODE: std::cell::RefCell<Option<FixtureMode>> = std::cell::RefCell::new(None);
+}
+
+/// Makes every following JSON-RPC request to be recorded until `save_recording` is called.
+pub fn start_recording() {
+    FIXTURE_MODE.with(|fixture_mode| {
+        *fixture_mode.borrow_mut() = Some(FixtureMode::Record(RpcFixture::default()))
+    });
+}
+
+pub fn save_recording(path: &std::path::Path) -> crate::CliResult {
+    let fixture = FIXTURE_MODE.with(|fixture_mode| match fixture_mode.borrow_mut().take() {
+        Some(FixtureMode::Record(fixture)) => fixture,
+        _ => RpcFixture::default(),
+    });
+    std::fs::write(path, serde_json::to_string_pretty(&fixture)?).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "Failed to save the RPC fixture {}: {}",
+            path.display(),
+            err
+        ))
+    })
+}
+
+/// Makes every following JSON-RPC request to be answered from the fixture instead of the network.
+pub fn start_replay(path: &std::path::Path) -> crate::CliResult {
+    let fixture = std::fs::read_to_string(path)
+        .map_err(|err| err.to_string())
+        .and_then(|fixture| {
+            serde_json::from_str::<RpcFixture>(&fixture).map_err(|err| err.to_string())
+        })
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to load the RPC fixture {}: {}",
+                path.display(),
+                err
+            ))
+        })?;
+    FIXTURE_MODE
+        .with(|fixture_mode| *fixture_mode.borrow_mut() = Some(FixtureMode::Replay(fixture)));
+    Ok(())
+}
+
+/// In replay mode every recorded interaction answers one request, in the recorded order, so
+/// repeated requests (e.g. retries) get the responses they got when recording.
+fn replayed_response(
+    method: &str,
+    params: &serde_json::Value,
+) -> Option<Result<serde_json::Value, RpcError>> {
+    FIXTURE_MODE.with(|fixture_mode| match &mut *fixture_mode.borrow_mut() {
+        Some(FixtureMode::Replay(fixture)) => Some(fixture.take_response(method, params)),
+        _ => None,
+    })
+}
+
+fn record_interaction(method: &str, params: &serde_json::Value, response: &serde_json::Value) {
+    FIXTURE_MODE.with(|fixture_mode| {
+        if let Some(FixtureMode::Record(fixture)) = &mut *fixture_mode.borrow_mut() {
+            fixture.interactions.push(RpcInteraction {
+                method: method.to_owned(),
+                params: params.clone(),
+                response: response.clone(),
+            })
+        }
+    });
+}
+
+impl RpcFixture {
+    fn take_response(
+        &mut self,
+        method: &str,
+        params: &serde_json::Value,
+    ) -> Result<serde_json::Value, RpcError> {
+        let index = self
+            .interactions
+            .iter()
+            .position(|interaction| interaction.method == method && &interaction.params == params)
+            .ok_or_else(|| {
+                rpc_error(format!(
+                    "The RPC fixture has no recorded response for {} {}",
+                    method, params
+                ))
+            })?;
+        Ok(self.interactions.remove(index).response)
+    }
+}
+
+/// Signed transactions are hidden entirely and so is any field that looks like key material,
+/// the rest of the params is logged as is.
+fn redacted_params(method: &str, params: &serde_json::Value) -> serde_json::Value {
+    fn redact_secrets(value: &serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(fields) => fields
+                .iter()
+                .map(|(name, value)| {
+                    let value = if ["private_key", "secret_key", "seed_phrase"]
+                        .iter()
+                        .any(|secret| name.contains(secret))
+                    {
+                        serde_json::Value::String("<redacted>".to_owned())
+                    } else {
+                        redact_secrets(value)
+                    };
+                    (name.clone(), value)
+                })
+                .collect(),
+            serde_json::Value::Array(items) => items.iter().map(redact_secrets).collect(),
+            _ => value.clone(),
+        }
+    }
+    if method.contains("broadcast_tx") {
+        return serde_json::json!(["<signed transaction redacted>"]);
+    }
+    redact_secrets(params)
+}
+
+/// Failures inside the node and timeouts may go away when the request is repeated; handler and
+/// request validation errors are about the request itself.
+fn is_transient(error: &serde_json::Value) -> bool {
+    match error["name"].as_str() {
+        Some("HANDLER_ERROR") => error["cause"]["name"] == "TIMEOUT_ERROR",
+        Some("REQUEST_VALIDATION_ERROR") => false,
+        _ => true,
+    }
+}
+
+/// The name of the cause of a handler error, e.g. `UNKNOWN_ACCOUNT`, for the callers that treat
+/// some of the handler errors as an answer rather than a failure.
+pub fn handler_error_cause(error: &RpcError) -> Option<&str> {
+    match &error.error_struct {
+        Some(near_jsonrpc_primitives::errors::RpcErrorKind::HandlerError(cause)) => {
+            cause["name"].as_str()
+        }
+        _ => None,
+    }
+}
+
+fn rpc_error(message: String) -> RpcError {
+    serde_json::from_value(serde_json::json!({
+        "code": -32000,
+        "message": "Server error",
+        "data": message,
+    }))
+    .expect("RpcError is expected to be deserialized from the legacy error fields")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rpc_client_attempts_with_backoff() {
+        let rpc_client = RpcClient::new(
+            vec![
+                "https://rpc.testnet.near.org".parse().unwrap(),
+                "https://rpc.fallback.example".parse().unwrap(),
+            ],
+            Default::default(),
+            crate::config::RpcConfig {
+                retries: 2,
+                initial_backoff_ms: 100,
+                request_timeout_secs: 10,
+            },
+        );
+        let attempts = rpc_client
+            .attempts()
+            .into_iter()
+            .map(|(url, backoff)| (url.host_str().unwrap().to_owned(), backoff.as_millis()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            attempts,
+            vec![
+                ("rpc.testnet.near.org".to_owned(), 0),
+                ("rpc.testnet.near.org".to_owned(), 100),
+                ("rpc.testnet.near.org".to_owned(), 200),
+                ("rpc.fallback.example".to_owned(), 0),
+                ("rpc.fallback.example".to_owned(), 100),
+                ("rpc.fallback.example".to_owned(), 200),
+            ]
+        );
+    }
+
+    #[test]
+    fn rpc_tracing_redacts_secrets() {
+        assert_eq!(
+            redacted_params(
+                "broadcast_tx_commit",
+                &serde_json::json!(["DgAAAHNlbmRlci50ZXN0bmV0"])
+            ),
+            serde_json::json!(["<signed transaction redacted>"])
+        );
+        assert_eq!(
+            redacted_params(
+                "query",
+                &serde_json::json!({
+                    "request_type": "view_account",
+                    "finality": "final",
+                    "account_id": "volodymyr.testnet",
+                    "nested": [{"private_key": "ed25519:secret"}],
+                })
+            ),
+            serde_json::json!({
+                "request_type": "view_account",
+                "finality": "final",
+                "account_id": "volodymyr.testnet",
+                "nested": [{"private_key": "<redacted>"}],
+            })
+        );
+    }
+
+    #[test]
+    fn rpc_fixture_replays_in_order() {
+        let params = serde_json::json!([]);
+        let mut fixture = RpcFixture {
+            interactions: vec![
+                RpcInteraction {
+                    method: "status".to_owned(),
+                    params: params.clone(),
+                    response: serde_json::json!({"result": 1}),
+                },
+                RpcInteraction {
+                    method: "status".to_owned(),
+                    params: params.clone(),
+                    response: serde_json::json!({"result": 2}),
+                },
+            ],
+        };
+        assert_eq!(
+            fixture.take_response("status", &params).unwrap(),
+            serde_json::json!({"result": 1})
+        );
+        assert!(fixture
+            .take_response("status", &serde_json::json!(["other"]))
+            .is_err());
+        assert_eq!(
+            fixture.take_response("status", &params).unwrap(),
+            serde_json::json!({"result": 2})
+        );
+        assert!(fixture.take_response("status", &params).is_err());
+    }
+
+    #[test]
+    fn rpc_error_is_transient() {
+        assert!(is_transient(&serde_json::json!({
+            "name": "INTERNAL_ERROR",
+            "cause": {"name": "INTERNAL_ERROR", "info": {}},
+        })));
+        assert!(is_transient(&serde_json::json!({
+            "name": "HANDLER_ERROR",
+            "cause": {"name": "TIMEOUT_ERROR", "info": {}},
+        })));
+        assert!(is_transient(&serde_json::json!({
+            "code": -32000,
+            "message": "Server error",
+            "data": "Timeout",
+        })));
+        assert!(!is_transient(&serde_json::json!({
+            "name": "HANDLER_ERROR",
+            "cause": {"name": "UNKNOWN_ACCOUNT", "info": {}},
+        })));
+        assert!(!is_transient(&serde_json::json!({
+            "name": "REQUEST_VALIDATION_ERROR",
+            "cause": {"name": "PARSE_ERROR", "info": {}},
+        })));
+    }
+
+    #[test]
+    fn rpc_error_handler_error_cause() {
+        let unknown_account: RpcError = serde_json::from_value(serde_json::json!({
+            "name": "HANDLER_ERROR",
+            "cause": {"name": "UNKNOWN_ACCOUNT", "info": {"requested_account_id": "volodymyr.testnet"}},
+            "code": -32000,
+            "message": "Server error",
+            "data": "account volodymyr.testnet does not exist while viewing",
+        }))
+        .unwrap();
+        assert_eq!(
+            handler_error_cause(&unknown_account),
+            Some("UNKNOWN_ACCOUNT")
+        );
+        assert_eq!(handler_error_cause(&rpc_error("Timeout".to_owned())), None);
+    }
+}